@@ -0,0 +1,144 @@
+use crate::{Coeff, DetEntry, ExactCoeff, Factor, Matrix, Mono, Poly, Sym, Zp, F64};
+use num_traits::Zero;
+
+/// Matrix entry with a polynomial form, for characteristic polynomials.
+pub trait CharPolyEntry: DetEntry {
+    type Coeff: Coeff;
+
+    fn into_poly(self) -> Poly<Self::Coeff>;
+}
+
+macro_rules! char_poly_entry_impl(
+    ($($t:ty),*) => {
+        $(
+            impl CharPolyEntry for $t {
+                type Coeff = $t;
+
+                fn into_poly(self) -> Poly<$t> {
+                    constant(self)
+                }
+            }
+        )*
+    }
+);
+
+char_poly_entry_impl!(i8, i16, i32, i64, i128, isize, F64, crate::BigInt);
+
+#[cfg(feature = "bigint")]
+char_poly_entry_impl!(crate::BigRational);
+
+/// `f32` and `f64` are not `Coeff`, their characteristic polynomials have `F64` coefficients.
+macro_rules! float_char_poly_entry_impl(
+    ($($t:ty),*) => {
+        $(
+            impl CharPolyEntry for $t {
+                type Coeff = F64;
+
+                fn into_poly(self) -> Poly<F64> {
+                    constant(F64(self.into()))
+                }
+            }
+        )*
+    }
+);
+
+float_char_poly_entry_impl!(f32, f64);
+
+impl<const P: u64> CharPolyEntry for Zp<P> {
+    type Coeff = Zp<P>;
+
+    fn into_poly(self) -> Poly<Zp<P>> {
+        constant(self)
+    }
+}
+
+impl<T: ExactCoeff> CharPolyEntry for Poly<T> {
+    type Coeff = T;
+
+    fn into_poly(self) -> Poly<T> {
+        self
+    }
+}
+
+impl<const P: u64> CharPolyEntry for Poly<Zp<P>> {
+    type Coeff = Zp<P>;
+
+    fn into_poly(self) -> Poly<Zp<P>> {
+        self
+    }
+}
+
+impl CharPolyEntry for Poly<F64> {
+    type Coeff = F64;
+
+    fn into_poly(self) -> Poly<F64> {
+        self
+    }
+}
+
+fn constant<T: Coeff>(coeff: T) -> Poly<T> {
+    if coeff.is_zero() {
+        return Poly::zero();
+    }
+    Poly {
+        terms: vec![Mono {
+            coeff,
+            factors: vec![],
+        }],
+    }
+}
+
+/// Coefficients of `det(x I - A)` from the highest power, by Berkowitz's
+/// algorithm, which only adds and multiplies entries.
+fn berkowitz<T: DetEntry>(rows: &[Vec<T>]) -> Vec<T> {
+    let mut coeffs = vec![T::one()];
+    for r in 0..rows.len() {
+        // A_{r+1} = [[A_r, col], [row, a]], the Toeplitz column is
+        // 1, -a, -row col, -row A_r col, ..., -row A_r^(r-1) col
+        let mut toeplitz = vec![T::one(), T::zero() - rows[r][r].clone()];
+        let mut col = (0..r).map(|i| rows[i][r].clone()).collect::<Vec<_>>();
+        for _ in 0..r {
+            let dot = (0..r).fold(T::zero(), |acc, j| {
+                acc + rows[r][j].clone() * col[j].clone()
+            });
+            toeplitz.push(T::zero() - dot);
+            col = (0..r)
+                .map(|i| {
+                    (0..r).fold(T::zero(), |acc, j| {
+                        acc + rows[i][j].clone() * col[j].clone()
+                    })
+                })
+                .collect();
+        }
+        coeffs = (0..r + 2)
+            .map(|i| {
+                (0..=i.min(r)).fold(T::zero(), |acc, j| {
+                    acc + toeplitz[i - j].clone() * coeffs[j].clone()
+                })
+            })
+            .collect();
+    }
+    coeffs
+}
+
+impl<T: CharPolyEntry, const N: usize> Matrix<T, N, N> {
+    /// Characteristic polynomial `det(var I - self)`, monic of degree `N`.
+    pub fn charpoly(&self, var: Sym) -> Poly<T::Coeff> {
+        let mut result = Poly::zero();
+        for (k, coeff) in berkowitz(&self.rows()).into_iter().enumerate() {
+            let power = (N - k) as i32;
+            let mut term = coeff.into_poly();
+            if power > 0 {
+                for mono in &mut term.terms {
+                    mono.factors.push(Factor {
+                        base: var.clone().into(),
+                        power,
+                    });
+                    mono.merge_factors();
+                }
+            }
+            result += term;
+        }
+        result
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Coeff, Mono, Poly};
+use num_traits::{CheckedAdd, CheckedMul, CheckedNeg, One};
+use std::fmt;
+
+/// Coefficient overflow, with the operation and operands that caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    /// `+`, `*` or `-` for negation
+    pub operation: &'static str,
+    pub lhs: String,
+    pub rhs: Option<String>,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.rhs {
+            Some(rhs) => write!(
+                f,
+                "coefficient overflow in {} {} {}",
+                self.lhs, self.operation, rhs
+            ),
+            None => write!(f, "coefficient overflow in {}{}", self.operation, self.lhs),
+        }
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+fn checked_add<T: Coeff + CheckedAdd>(a: &T, b: &T) -> Result<T, OverflowError> {
+    a.checked_add(b).ok_or_else(|| OverflowError {
+        operation: "+",
+        lhs: a.to_string(),
+        rhs: Some(b.to_string()),
+    })
+}
+
+fn checked_mul<T: Coeff + CheckedMul>(a: &T, b: &T) -> Result<T, OverflowError> {
+    a.checked_mul(b).ok_or_else(|| OverflowError {
+        operation: "*",
+        lhs: a.to_string(),
+        rhs: Some(b.to_string()),
+    })
+}
+
+fn checked_neg<T: Coeff + CheckedNeg>(a: &T) -> Result<T, OverflowError> {
+    a.checked_neg().ok_or_else(|| OverflowError {
+        operation: "-",
+        lhs: a.to_string(),
+        rhs: None,
+    })
+}
+
+impl<T: Coeff + CheckedMul> Mono<T> {
+    /// Product failing on coefficient overflow.
+    pub fn try_mul(&self, rhs: &Mono<T>) -> Result<Mono<T>, OverflowError> {
+        let coeff = checked_mul(&self.coeff, &rhs.coeff)?;
+        let mut factors = Vec::with_capacity(self.factors.len() + rhs.factors.len());
+        factors.extend(self.factors.iter().cloned());
+        factors.extend(rhs.factors.iter().cloned());
+        let mut result = Mono { coeff, factors };
+        result.merge_factors();
+        Ok(result)
+    }
+}
+
+/// Arithmetic failing on coefficient overflow instead of wrapping or panicking.
+impl<T: Coeff + CheckedAdd + CheckedMul + CheckedNeg> Poly<T> {
+    /// Like `merge_terms`, leaving the terms unchanged on overflow.
+    pub fn try_merge_terms(&mut self) -> Result<(), OverflowError> {
+        let mut merged = self.clone();
+        merged.merge_terms_with(|coeff, other| {
+            *coeff = checked_add(coeff, &other)?;
+            Ok(())
+        })?;
+        *self = merged;
+        Ok(())
+    }
+
+    pub fn try_add(&self, rhs: &Poly<T>) -> Result<Poly<T>, OverflowError> {
+        let mut terms = Vec::with_capacity(self.terms.len() + rhs.terms.len());
+        terms.extend(self.terms.iter().cloned());
+        terms.extend(rhs.terms.iter().cloned());
+        let mut result = Poly { terms };
+        result.try_merge_terms()?;
+        Ok(result)
+    }
+
+    pub fn try_neg(&self) -> Result<Poly<T>, OverflowError> {
+        let terms = self
+            .terms
+            .iter()
+            .map(|term| {
+                Ok(Mono {
+                    coeff: checked_neg(&term.coeff)?,
+                    factors: term.factors.clone(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Poly { terms })
+    }
+
+    pub fn try_sub(&self, rhs: &Poly<T>) -> Result<Poly<T>, OverflowError> {
+        self.try_add(&rhs.try_neg()?)
+    }
+
+    pub fn try_mul(&self, rhs: &Poly<T>) -> Result<Poly<T>, OverflowError> {
+        let mut terms = Vec::with_capacity(self.terms.len() * rhs.terms.len());
+        for term in &self.terms {
+            for other in &rhs.terms {
+                terms.push(term.try_mul(other)?);
+            }
+        }
+        let mut result = Poly { terms };
+        result.try_merge_terms()?;
+        Ok(result)
+    }
+
+    /// Power by repeated squaring.
+    pub fn try_pow(&self, mut n: u32) -> Result<Poly<T>, OverflowError> {
+        let mut result = Poly::one();
+        let mut base = self.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.try_mul(&base)?;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.try_mul(&base)?;
+            }
+        }
+        Ok(result)
+    }
+}
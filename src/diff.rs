@@ -0,0 +1,104 @@
+use crate::function::{coeff_of, derivative_at};
+use crate::{Base, Coeff, Der, Factor, Mono, Poly, Sym};
+use num_traits::{FromPrimitive, Num};
+
+fn der<T: Coeff>(func: &str, param: &Poly<T>, power: i32) -> Factor<T> {
+    Factor {
+        base: Base::Der(Der {
+            func: func.to_string().into(),
+            param: param.clone(),
+        }),
+        power,
+    }
+}
+
+/// Divide the term by the integer `denominator`, keeping the factor `denominator^-1`
+/// when integer coefficients cannot hold the quotient.
+fn divide<T: Coeff + Num>(mono: &mut Mono<T>, denominator: &T) {
+    if denominator.is_one() {
+        return;
+    }
+    let exact = !denominator.is_zero()
+        && (!(T::one() / denominator.clone()).is_zero()
+            || (mono.coeff.clone() % denominator.clone()).is_zero());
+    if exact {
+        mono.coeff = mono.coeff.clone() / denominator.clone();
+    } else {
+        mono.factors.push(Factor {
+            base: Base::Poly(Poly::from(Mono {
+                coeff: denominator.clone(),
+                factors: vec![],
+            })),
+            power: -1,
+        });
+    }
+}
+
+/// Derivative of the function `func` at `param` over its denominator,
+/// written as the function `f'` when unknown.
+fn func_derivative<T: Coeff + Num + FromPrimitive>(func: &str, param: &Poly<T>) -> (Poly<T>, T) {
+    derivative_at(func, param).unwrap_or_else(|| {
+        let derivative = Poly::from(Mono {
+            coeff: T::one(),
+            factors: vec![der(&format!("{}'", func), param, 1)],
+        });
+        (derivative, T::one())
+    })
+}
+
+/// Derivative of a factor base with respect to `var`, over a denominator.
+fn base_derivative<T: Coeff + Num + FromPrimitive>(base: &Base<T>, var: &Sym) -> (Poly<T>, T) {
+    match base {
+        Base::Sym(sym) if sym == var => (
+            Poly::from(Mono {
+                coeff: T::one(),
+                factors: vec![],
+            }),
+            T::one(),
+        ),
+        Base::Sym(_) => (Poly { terms: vec![] }, T::one()),
+        Base::Poly(poly) => (poly.diff(var), T::one()),
+        Base::Der(der) => {
+            let inner = der.param.diff(var);
+            if inner.terms.is_empty() {
+                return (inner, T::one());
+            }
+            let (derivative, denominator) = func_derivative(&der.func, &der.param);
+            (derivative * inner, denominator)
+        }
+    }
+}
+
+impl<T: Coeff + Num + FromPrimitive> Poly<T> {
+    /// Derivative with respect to `var`, by the product, power and chain rules.
+    ///
+    /// Derivatives of registered functions are used, the derivative of
+    /// another function `f` is written as the function `f'`.
+    pub fn diff(&self, var: &Sym) -> Poly<T> {
+        let mut terms = vec![];
+        for term in &self.terms {
+            for (index, factor) in term.factors.iter().enumerate() {
+                let (derivative, denominator) = base_derivative(&factor.base, var);
+                if derivative.terms.is_empty() {
+                    continue;
+                }
+                // p base^(p - 1) base'
+                let mut factors = term.factors.clone();
+                factors[index].power -= 1;
+                let coeff = term.coeff.clone() * coeff_of::<T>(factor.power as i64);
+                for inner in derivative.terms {
+                    let mut mono = Mono {
+                        coeff: coeff.clone() * inner.coeff,
+                        factors: factors.iter().cloned().chain(inner.factors).collect(),
+                    };
+                    divide(&mut mono, &denominator);
+                    mono.merge_factors();
+                    terms.push(mono);
+                }
+            }
+        }
+        let mut result = Poly { terms };
+        result.merge_terms();
+        result
+    }
+}
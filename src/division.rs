@@ -0,0 +1,140 @@
+use crate::mpoly::{coeff_divides, MPoly};
+use crate::{Base, Poly, SignedCoeff, Sym, TermOrder};
+use std::ops::Div;
+
+impl<T: SignedCoeff> Poly<T> {
+    /// Pseudo-division with respect to `var`.
+    ///
+    /// Returns the quotient `q` and the remainder `r` such that `lc^k self = q divisor + r`,
+    /// where `lc` is the leading coefficient of `divisor` in `var`, `k = max(m - n + 1, 0)`
+    /// for the degrees `m` and `n` of `self` and `divisor`, and `r` has degree less than `n`.
+    pub fn pdiv(&self, divisor: &Poly<T>, var: &Sym) -> (Poly<T>, Poly<T>) {
+        let (polys, vars, x) = from_polys_with(&[self, divisor], var);
+        assert!(!polys[1].is_zero(), "division by zero polynomial");
+        let (q, r) = pdiv(&polys[0], &polys[1], x);
+        (q.to_poly(&vars), r.to_poly(&vars))
+    }
+
+    /// Pseudo-remainder with respect to `var`, see [`Poly::pdiv`].
+    pub fn prem(&self, divisor: &Poly<T>, var: &Sym) -> Poly<T> {
+        self.pdiv(divisor, var).1
+    }
+
+    /// Division by a list of divisors under a monomial order.
+    ///
+    /// Returns the quotients and the remainder, `self = sum(q_i divisor_i) + r`.
+    /// A term is reduced by the first divisor whose leading monomial and leading coefficient
+    /// divide it, otherwise it is moved to the remainder.
+    pub fn divide(
+        &self,
+        divisors: &[Poly<T>],
+        order: impl Into<TermOrder<T>>,
+    ) -> (Vec<Poly<T>>, Poly<T>) {
+        let order = order.into();
+        let mut polys = vec![self];
+        polys.extend(divisors);
+        let (polys, vars) = MPoly::from_polys_in(&polys, &order.vars);
+        let (quotients, remainder) = divide(&polys[0], &polys[1..], &order);
+        (
+            quotients.iter().map(|q| q.to_poly(&vars)).collect(),
+            remainder.to_poly(&vars),
+        )
+    }
+}
+
+/// Exact division, `None` if the divisor is zero or does not divide the polynomial.
+impl<T: SignedCoeff> Div for Poly<T> {
+    type Output = Option<Poly<T>>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let (polys, vars) = MPoly::from_polys(&[&self, &rhs]);
+        polys[0]
+            .div_exact(&polys[1])
+            .map(|quotient| quotient.to_poly(&vars))
+    }
+}
+
+/// Convert polynomials over a common list of variables containing `var`, returning its index.
+pub(crate) fn from_polys_with<T: SignedCoeff>(
+    polys: &[&Poly<T>],
+    var: &Sym,
+) -> (Vec<MPoly<T>>, Vec<Base<T>>, usize) {
+    let (mut polys, mut vars) = MPoly::from_polys(polys);
+    let base = Base::Sym(var.clone());
+    let x = match vars.iter().position(|v| *v == base) {
+        Some(x) => x,
+        None => {
+            vars.push(base);
+            for poly in &mut polys {
+                poly.resize(vars.len());
+            }
+            vars.len() - 1
+        }
+    };
+    (polys, vars, x)
+}
+
+/// Pseudo-division of `a` by `b` with respect to variable `x`.
+pub(crate) fn pdiv<T: SignedCoeff>(a: &MPoly<T>, b: &MPoly<T>, x: usize) -> (MPoly<T>, MPoly<T>) {
+    let n = b.degree(x);
+    let lc = b.lc_in(x);
+    let mut q = MPoly::constant(a.nvars, T::zero());
+    let mut r = a.clone();
+    let mut k = (a.degree(x) - n + 1).max(0);
+    while !r.is_zero() && r.degree(x) >= n {
+        let s = r.lc_in(x).mul_var(x, r.degree(x) - n);
+        q = q.mul(&lc).add(&s);
+        r = r.mul(&lc).sub(&s.mul(b));
+        k -= 1;
+    }
+    for _ in 0..k {
+        q = q.mul(&lc);
+        r = r.mul(&lc);
+    }
+    (q, r)
+}
+
+/// Division of `f` by the divisors under `order`.
+pub(crate) fn divide<T: SignedCoeff>(
+    f: &MPoly<T>,
+    divisors: &[MPoly<T>],
+    order: &TermOrder<T>,
+) -> (Vec<MPoly<T>>, MPoly<T>) {
+    let zero = MPoly::constant(f.nvars, T::zero());
+    let mut quotients = vec![zero.clone(); divisors.len()];
+    let mut remainder = zero;
+    let mut p = f.clone();
+    while let Some((exps, coeff)) = p.leading(order).cloned() {
+        let term = MPoly {
+            nvars: f.nvars,
+            terms: vec![(exps.clone(), coeff.clone())],
+        };
+        let reducer = divisors.iter().enumerate().find_map(|(i, g)| {
+            let (lead_exps, lead_coeff) = g.leading(order)?;
+            let exps = exps
+                .iter()
+                .zip(lead_exps)
+                .map(|(a, b)| a - b)
+                .collect::<Vec<_>>();
+            if exps.iter().any(|e| *e < 0) || !coeff_divides(lead_coeff, &coeff) {
+                return None;
+            }
+            Some((i, exps, coeff.clone() / lead_coeff.clone()))
+        });
+        match reducer {
+            Some((i, exps, coeff)) => {
+                let quotient = MPoly {
+                    nvars: f.nvars,
+                    terms: vec![(exps, coeff)],
+                };
+                p = p.sub(&quotient.mul(&divisors[i]));
+                quotients[i] = quotients[i].add(&quotient);
+            }
+            None => {
+                p = p.sub(&term);
+                remainder = remainder.add(&term);
+            }
+        }
+    }
+    (quotients, remainder)
+}
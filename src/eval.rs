@@ -0,0 +1,108 @@
+use crate::{lookup_function, Base, Coeff, Mono, Poly, Sym};
+use num_traits::{FromPrimitive, Num, ToPrimitive};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Error of evaluating a polynomial numerically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    /// symbol without a value in the environment
+    UnboundSymbol(Sym),
+    /// function not found in the registry
+    UnknownFunction(String),
+    /// registered function taking other than one argument
+    Arity { func: String, arity: usize },
+    /// negative power of zero
+    DivisionByZero,
+    /// number not representable in the target type
+    Conversion,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnboundSymbol(sym) => write!(f, "unbound symbol `{}`", sym.0),
+            Self::UnknownFunction(func) => write!(f, "unknown function `{}`", func),
+            Self::Arity { func, arity } => {
+                write!(f, "function `{}` takes {} arguments, not 1", func, arity)
+            }
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::Conversion => write!(f, "number out of range"),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+fn eval_base<T, F>(base: &Base<T>, env: &HashMap<Sym, F>) -> Result<F, EvalError>
+where
+    T: Coeff + ToPrimitive,
+    F: Num + Clone + FromPrimitive + ToPrimitive,
+{
+    match base {
+        Base::Sym(sym) => env
+            .get(sym)
+            .cloned()
+            .ok_or_else(|| EvalError::UnboundSymbol(sym.clone())),
+        Base::Poly(poly) => poly.eval_in(env),
+        Base::Der(der) => {
+            let function = lookup_function(&der.func)
+                .ok_or_else(|| EvalError::UnknownFunction(der.func.to_string()))?;
+            if function.arity() != 1 {
+                return Err(EvalError::Arity {
+                    func: der.func.to_string(),
+                    arity: function.arity(),
+                });
+            }
+            let arg = der.param.eval_in(env)?;
+            let arg = arg.to_f64().ok_or(EvalError::Conversion)?;
+            F::from_f64(function.eval(&[arg])).ok_or(EvalError::Conversion)
+        }
+    }
+}
+
+fn eval_mono<T, F>(term: &Mono<T>, env: &HashMap<Sym, F>) -> Result<F, EvalError>
+where
+    T: Coeff + ToPrimitive,
+    F: Num + Clone + FromPrimitive + ToPrimitive,
+{
+    let coeff = &term.coeff;
+    // fractional coefficients would be truncated by `to_i64`
+    let mut value = coeff
+        .to_i64()
+        .filter(|n| coeff.to_f64().is_none_or(|x| x == *n as f64))
+        .and_then(F::from_i64)
+        .or_else(|| coeff.to_f64().and_then(F::from_f64))
+        .ok_or(EvalError::Conversion)?;
+    for factor in &term.factors {
+        let base = eval_base(&factor.base, env)?;
+        let power = num_traits::pow(base.clone(), factor.power.unsigned_abs() as usize);
+        if factor.power >= 0 {
+            value = value * power;
+        } else if power.is_zero() {
+            return Err(EvalError::DivisionByZero);
+        } else {
+            value = value / power;
+        }
+    }
+    Ok(value)
+}
+
+impl<T: Coeff + ToPrimitive> Poly<T> {
+    /// Numeric value with symbols bound by `env` and functions taken from the registry.
+    pub fn eval(&self, env: &HashMap<Sym, f64>) -> Result<f64, EvalError> {
+        self.eval_in(env)
+    }
+
+    /// Numeric value in any number type, functions being evaluated through `f64`.
+    pub fn eval_in<F>(&self, env: &HashMap<Sym, F>) -> Result<F, EvalError>
+    where
+        F: Num + Clone + FromPrimitive + ToPrimitive,
+    {
+        let mut sum = F::zero();
+        for term in &self.terms {
+            sum = sum + eval_mono(term, env)?;
+        }
+        Ok(sum)
+    }
+}
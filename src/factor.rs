@@ -0,0 +1,367 @@
+use crate::gcd::{content_in, gcd};
+use crate::hensel::{choose_point, evaluate, lift, EXACT_BITS};
+use crate::mpoly::MPoly;
+use crate::upoly::{self, Rng};
+use crate::{Base, Factor, IntegerCoeff, Poly, Sym};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use std::collections::BTreeMap;
+
+impl<T: IntegerCoeff> Poly<T> {
+    /// Factorize the polynomial over the integers.
+    ///
+    /// Returns the content, carrying the sign of the polynomial, and the irreducible factors
+    /// with their multiplicities. Monomial factors keep their base, e.g. `Base::Sym`,
+    /// other factors are given as `Base::Poly` with positive leading coefficient.
+    ///
+    /// The content is returned apart because a constant has no `Factor` form, and keeping it
+    /// out of the list leaves every listed factor irreducible and non-constant.
+    /// The polynomial equals the content times the product of the factors.
+    /// The factors are computed over `BigInt`, irreducible factors with coefficients out of
+    /// range of `T` are left multiplied together.
+    pub fn factorize(&self) -> (T, Vec<Factor<T>>) {
+        let mut vars = vec![];
+        let poly = MPoly::from_poly(self, &mut vars);
+        if poly.is_zero() {
+            return (T::zero(), vec![]);
+        }
+        let mut factors = vec![];
+        let shift = poly.min_exponents();
+        for (base, power) in vars.iter().zip(&shift) {
+            if *power != 0 {
+                factors.push(Factor {
+                    base: base.clone(),
+                    power: *power,
+                });
+            }
+        }
+        let poly = poly.shift(&shift);
+        let content = poly.content();
+        let poly = poly.div_coeff(content.clone());
+        // lifting and Yun's gcds overflow `T` long before the factors do
+        let factors_big = factor_primitive(&poly.to_bigint());
+        for (factor, power) in from_bigint_factors(factors_big, &poly) {
+            factors.push(Factor {
+                base: Base::Poly(factor.to_poly(&vars)),
+                power: power as i32,
+            });
+        }
+        factors.sort();
+        (content, factors)
+    }
+
+    /// Square-free decomposition with respect to `var`, by Yun's algorithm.
+    ///
+    /// Returns the content, the part of the polynomial free of `var`, and the square-free
+    /// pairwise coprime factors grouped by multiplicity, each given with positive leading
+    /// coefficient. A negative power of `var` is kept as a `Base::Sym` factor.
+    /// If the content or a factor has coefficients out of range of `T`, the polynomial is
+    /// returned as the content with no factors.
+    pub fn square_free(&self, var: &Sym) -> (Poly<T>, Vec<Factor<T>>) {
+        let mut vars = vec![];
+        let poly = MPoly::from_poly(self, &mut vars);
+        let base = Base::Sym(var.clone());
+        let Some(x) = vars.iter().position(|v| *v == base) else {
+            return (poly.to_poly(&vars), vec![]);
+        };
+        let mut factors = vec![];
+        let mut shift = poly.min_exponents();
+        if shift[x] < 0 {
+            factors.push(Factor {
+                base,
+                power: shift[x],
+            });
+        } else {
+            shift[x] = 0;
+        }
+        let poly = poly.shift(&shift).to_bigint();
+        shift[x] = 0;
+        let content = content_in(&poly, x);
+        let poly = poly.div_exact(&content).unwrap();
+        let mut product = MPoly::constant(poly.nvars, BigInt::one());
+        for (factor, power) in square_free_in(&poly, x) {
+            for _ in 0..power {
+                product = product.mul(&factor);
+            }
+            let Some(factor) = MPoly::from_bigint(&factor) else {
+                return (self.clone(), vec![]);
+            };
+            factors.push(Factor {
+                base: Base::Poly(factor.to_poly(&vars)),
+                power: power as i32,
+            });
+        }
+        // the factors are normalized, the sign goes to the content
+        let content = content.mul(&poly.div_exact(&product).unwrap());
+        let Some(content) = MPoly::<T>::from_bigint(&content) else {
+            return (self.clone(), vec![]);
+        };
+        let monomial = shift.iter().map(|e| -e).collect::<Vec<_>>();
+        (content.shift(&monomial).to_poly(&vars), factors)
+    }
+}
+
+/// Factorize a polynomial free of integer and monomial content.
+fn factor_primitive<T: IntegerCoeff>(f: &MPoly<T>) -> Vec<(MPoly<T>, u32)> {
+    let vars = f.variables();
+    if vars.is_empty() {
+        return vec![];
+    }
+    if let [x] = vars[..] {
+        let dense = f
+            .to_dense(x)
+            .iter()
+            .map(IntegerCoeff::to_bigint)
+            .collect::<Vec<_>>();
+        let factors = upoly::factor(&dense)
+            .into_iter()
+            .map(|(factor, power)| Some((from_dense(f.nvars, x, &factor)?, power)))
+            .collect::<Option<Vec<_>>>();
+        return factors.unwrap_or_else(|| vec![(f.clone(), 1)]);
+    }
+    // main variable of lowest degree gives the fewest univariate factors
+    let x = *vars.iter().min_by_key(|&&x| f.degree(x)).unwrap();
+    let content = content_in(f, x);
+    let mut result = factor_primitive(&content);
+    let f = f.div_exact(&content).unwrap().normalize();
+    for (g, power) in square_free_in(&f, x) {
+        for factor in factor_square_free(&g, x) {
+            result.push((factor, power));
+        }
+    }
+    result
+}
+
+/// Factors computed over `BigInt`, those out of range of `T` left multiplied together,
+/// or `f` itself if their product is out of range too.
+fn from_bigint_factors<T: IntegerCoeff>(
+    factors: Vec<(MPoly<BigInt>, u32)>,
+    f: &MPoly<T>,
+) -> Vec<(MPoly<T>, u32)> {
+    let mut result = vec![];
+    let mut rest = MPoly::constant(f.nvars, BigInt::one());
+    for (factor, power) in factors {
+        match MPoly::from_bigint(&factor) {
+            Some(factor) => result.push((factor, power)),
+            None => {
+                for _ in 0..power {
+                    rest = rest.mul(&factor);
+                }
+            }
+        }
+    }
+    if rest.is_constant() {
+        return result;
+    }
+    match MPoly::from_bigint(&rest) {
+        Some(rest) => {
+            result.push((rest.normalize(), 1));
+            result
+        }
+        None => vec![(f.clone(), 1)],
+    }
+}
+
+fn from_dense<T: IntegerCoeff>(nvars: usize, x: usize, dense: &[BigInt]) -> Option<MPoly<T>> {
+    let dense = dense
+        .iter()
+        .map(T::from_bigint)
+        .collect::<Option<Vec<_>>>()?;
+    Some(MPoly::from_dense(nvars, x, &dense))
+}
+
+/// Square-free decomposition of a polynomial primitive with respect to `x`, by Yun's algorithm.
+pub(crate) fn square_free_in<T: IntegerCoeff>(f: &MPoly<T>, x: usize) -> Vec<(MPoly<T>, u32)> {
+    let df = f.derivative(x);
+    let a = gcd(f, &df);
+    let mut b = f.div_exact(&a).unwrap();
+    let c = df.div_exact(&a).unwrap();
+    let mut d = c.sub(&b.derivative(x));
+    let mut result = vec![];
+    let mut power = 1;
+    while b.degree(x) > 0 {
+        let a = gcd(&b, &d);
+        b = b.div_exact(&a).unwrap();
+        let c = d.div_exact(&a).unwrap();
+        d = c.sub(&b.derivative(x));
+        if a.degree(x) > 0 {
+            result.push((a, power));
+        }
+        power += 1;
+    }
+    result
+}
+
+/// Irreducible factors of a polynomial square-free and primitive with respect to `x`.
+fn factor_square_free<T: IntegerCoeff>(f: &MPoly<T>, x: usize) -> Vec<MPoly<T>> {
+    let degree = f.degree(x);
+    let vars = f
+        .variables()
+        .into_iter()
+        .filter(|v| *v != x)
+        .collect::<Vec<_>>();
+    if vars.is_empty() {
+        return factor_primitive(f).into_iter().map(|(g, _)| g).collect();
+    }
+    if degree <= 1 {
+        return vec![f.clone()];
+    }
+    // lifting finds every factor only if their coefficients stay below the modulus
+    if coefficient_bits(f) > EXACT_BITS {
+        return factor_kronecker(f);
+    }
+
+    // univariate images at a few good points, keeping the one with the fewest factors
+    let mut rng = Rng::new();
+    let mut best: Option<(Vec<i128>, Vec<Vec<BigInt>>)> = None;
+    let mut found = 0;
+    for attempt in 0..100 {
+        let point = choose_point(f.nvars, &vars, attempt, &mut rng);
+        let image = evaluate(f, x, &point);
+        if image.len() as i32 != degree + 1 {
+            continue;
+        }
+        let image = upoly::primitive(&image);
+        let derivative = upoly::derivative(&image);
+        if upoly::gcd(&image, &derivative).len() > 1 {
+            continue;
+        }
+        let factors = upoly::factor_square_free(&image);
+        if factors.len() == 1 {
+            return vec![f.clone()];
+        }
+        if best.as_ref().is_none_or(|(_, b)| factors.len() < b.len()) {
+            best = Some((point, factors));
+        }
+        found += 1;
+        if found == 3 {
+            break;
+        }
+    }
+    let Some((point, mut images)) = best else {
+        return factor_kronecker(f);
+    };
+
+    // lift subsets of the univariate factors, from the smallest ones
+    let mut result = vec![];
+    let mut f = f.clone();
+    let mut size = 1;
+    'outer: while 2 * size <= images.len() {
+        let mut subset = (0..size).collect::<Vec<_>>();
+        loop {
+            let g0 = subset
+                .iter()
+                .fold(vec![BigInt::one()], |acc, &i| upoly::mul(&acc, &images[i]));
+            let h0 = (0..images.len())
+                .filter(|i| !subset.contains(i))
+                .fold(vec![BigInt::one()], |acc, i| upoly::mul(&acc, &images[i]));
+            let lc = f.lc_in(x);
+            if let Some(g) = lift(&f.mul(&lc), x, &point, &g0, &h0, &lc, &lc) {
+                let g = g.div_exact(&content_in(&g, x)).unwrap().normalize();
+                if let Some(quotient) = f.div_exact(&g) {
+                    result.push(g);
+                    f = quotient.normalize();
+                    for &i in subset.iter().rev() {
+                        images.remove(i);
+                    }
+                    continue 'outer;
+                }
+            }
+            if !upoly::next_subset(&mut subset, images.len()) {
+                break;
+            }
+        }
+        size += 1;
+    }
+    result.push(f);
+    result
+}
+
+/// Bits of a bound on the coefficients of `lc_in(x) g` for a factor `g` of `f`.
+///
+/// These divide `f lc_in(x)`, whose Mahler measure is at most `|f|_2^2`, so by Gelfond's
+/// inequality their coefficients are at most `2^(2 d) |f|_2^2`, `d` the sum of the degrees.
+fn coefficient_bits<T: IntegerCoeff>(f: &MPoly<T>) -> u64 {
+    let degrees = f
+        .variables()
+        .into_iter()
+        .map(|v| f.degree(v) as u64)
+        .sum::<u64>();
+    let norm = f
+        .terms
+        .iter()
+        .map(|(_, coeff)| coeff.to_bigint().pow(2))
+        .sum::<BigInt>();
+    2 * degrees + norm.bits()
+}
+
+/// Irreducible factors of a square-free primitive polynomial by the Kronecker substitution
+/// `y_j = x^(b^j)`, slower than lifting but free of its limits on the coefficients.
+fn factor_kronecker<T: IntegerCoeff>(f: &MPoly<T>) -> Vec<MPoly<T>> {
+    let vars = f.variables();
+    // the base exceeds every degree, so the substitution is invertible on the factors
+    let base = vars.iter().map(|&v| f.degree(v)).max().unwrap_or(0) as usize + 1;
+    let mut image = vec![];
+    for (exps, coeff) in &f.terms {
+        let power = vars
+            .iter()
+            .rev()
+            .fold(0, |acc, &v| acc * base + exps[v] as usize);
+        if image.len() <= power {
+            image.resize(power + 1, BigInt::zero());
+        }
+        image[power] = coeff.to_bigint();
+    }
+    let restore = |dense: &[BigInt]| {
+        let mut terms = BTreeMap::new();
+        for (power, coeff) in dense.iter().enumerate() {
+            if coeff.is_zero() {
+                continue;
+            }
+            let mut exps = vec![0; f.nvars];
+            let mut power = power;
+            for &v in &vars {
+                exps[v] = (power % base) as i32;
+                power /= base;
+            }
+            terms.insert(exps, T::from_bigint(coeff)?);
+        }
+        let g = MPoly::from_map(f.nvars, terms);
+        Some(g.div_coeff(g.content()).normalize())
+    };
+
+    let mut images = vec![];
+    for (factor, power) in upoly::factor(&upoly::primitive(&image)) {
+        for _ in 0..power {
+            images.push(factor.clone());
+        }
+    }
+    // combine the univariate factors, from the smallest subsets
+    let mut result = vec![];
+    let mut f = f.clone();
+    let mut size = 1;
+    'outer: while 2 * size <= images.len() {
+        let mut subset = (0..size).collect::<Vec<_>>();
+        loop {
+            let product = subset
+                .iter()
+                .fold(vec![BigInt::one()], |acc, &i| upoly::mul(&acc, &images[i]));
+            if let Some(g) = restore(&product) {
+                if let Some(quotient) = f.div_exact(&g) {
+                    result.push(g);
+                    f = quotient.normalize();
+                    for &i in subset.iter().rev() {
+                        images.remove(i);
+                    }
+                    continue 'outer;
+                }
+            }
+            if !upoly::next_subset(&mut subset, images.len()) {
+                break;
+            }
+        }
+        size += 1;
+    }
+    result.push(f);
+    result
+}
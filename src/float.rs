@@ -0,0 +1,217 @@
+use crate::{Coeff, Poly};
+use num_traits::{FromPrimitive, Num, One, ParseFloatError, Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// `f64` with a total order, usable as a polynomial coefficient.
+///
+/// `-0.0` equals `0.0` and all NaNs are equal, ordered after every number.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct F64(pub f64);
+
+impl F64 {
+    fn key(self) -> f64 {
+        if self.0 == 0.0 {
+            0.0
+        } else if self.0.is_nan() {
+            f64::NAN
+        } else {
+            self.0
+        }
+    }
+}
+
+impl Coeff for F64 {
+    fn is_negative(&self) -> bool {
+        self.0 < 0.0
+    }
+}
+
+impl From<f64> for F64 {
+    fn from(value: f64) -> Self {
+        F64(value)
+    }
+}
+
+impl From<F64> for f64 {
+    fn from(value: F64) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq for F64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for F64 {}
+
+impl PartialOrd for F64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for F64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.key().total_cmp(&other.key()),
+        }
+    }
+}
+
+impl Hash for F64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().to_bits().hash(state);
+    }
+}
+
+impl fmt::Display for F64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.key())
+    }
+}
+
+macro_rules! float_op_impl(
+    ($($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident);*) => {
+        $(
+            impl $op for F64 {
+                type Output = Self;
+
+                fn $fn(self, rhs: Self) -> Self::Output {
+                    F64(self.0.$fn(rhs.0))
+                }
+            }
+
+            impl $op_assign for F64 {
+                fn $fn_assign(&mut self, rhs: Self) {
+                    self.0.$fn_assign(rhs.0);
+                }
+            }
+        )*
+    }
+);
+
+float_op_impl!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign
+);
+
+impl Rem for F64 {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        F64(self.0 % rhs.0)
+    }
+}
+
+impl Neg for F64 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        F64(-self.0)
+    }
+}
+
+impl Zero for F64 {
+    fn zero() -> Self {
+        F64(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+}
+
+impl One for F64 {
+    fn one() -> Self {
+        F64(1.0)
+    }
+}
+
+/// Parses decimals and fractions `n/d`.
+impl Num for F64 {
+    type FromStrRadixErr = ParseFloatError;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        match str.split_once('/') {
+            Some((numer, denom)) => Ok(F64(
+                f64::from_str_radix(numer, radix)? / f64::from_str_radix(denom, radix)?
+            )),
+            None => f64::from_str_radix(str, radix).map(F64),
+        }
+    }
+}
+
+impl Signed for F64 {
+    fn abs(&self) -> Self {
+        F64(self.0.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        F64((self.0 - other.0).max(0.0))
+    }
+
+    fn signum(&self) -> Self {
+        F64(Signed::signum(&self.0))
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0 > 0.0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0 < 0.0
+    }
+}
+
+impl ToPrimitive for F64 {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0)
+    }
+}
+
+impl FromPrimitive for F64 {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(F64(n as f64))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(F64(n as f64))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(F64(n))
+    }
+}
+
+impl Poly<F64> {
+    /// Like `merge_terms`, also dropping terms whose coefficient is at most `tolerance` times
+    /// the largest coefficient before merging, such as rounding noise left by cancellation.
+    pub fn merge_terms_approx(&mut self, tolerance: f64) {
+        let scale = self
+            .terms
+            .iter()
+            .map(|term| term.coeff.0.abs())
+            .fold(0.0, f64::max);
+        self.merge_terms();
+        self.terms
+            .retain(|term| term.coeff.0.abs() > tolerance * scale);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{Base, Coeff, Der, Factor, Mono, ParseError, Poly, Sym};
+use num_traits::FromPrimitive;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, OnceLock, RwLock};
+
+/// Symmetry of a function under negation of its argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Parity {
+    /// `f(-x) = f(x)`
+    Even,
+    /// `f(-x) = -f(x)`
+    Odd,
+    #[default]
+    None,
+}
+
+/// Function applied to polynomials as `Base::Der` factors.
+pub trait Function: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn arity(&self) -> usize {
+        1
+    }
+
+    /// Numeric value at `args`, which has `arity` elements.
+    fn eval(&self, args: &[f64]) -> f64;
+
+    /// Derivative as an expression of `x`, e.g. `- sin(x)` for `cos`.
+    fn derivative(&self) -> Option<&Derivative> {
+        None
+    }
+
+    fn parity(&self) -> Parity {
+        Parity::None
+    }
+
+    /// Integer arguments with integer values, e.g. `(0, 1)` for `cos`.
+    fn special_values(&self) -> &[(i32, i32)] {
+        &[]
+    }
+}
+
+/// Derivative of a function of one argument, an expression of `x` over a positive integer,
+/// built once and instantiated for every coefficient type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Derivative {
+    pub numerator: Poly<i64>,
+    pub denominator: i64,
+}
+
+impl Derivative {
+    /// Parse an expression of `x` in the syntax of `Poly::parse`.
+    ///
+    /// Integer factors with negative powers go to the denominator,
+    /// e.g. `(2)^-1 sqrt(x)^-1` for `sqrt`.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let poly = Poly::<i64>::parse(text)?;
+        let mut terms = vec![];
+        for mut term in poly.terms {
+            let mut denominator = 1;
+            term.factors.retain(|factor| match &factor.base {
+                Base::Poly(base) if factor.power < 0 => match base.terms.as_slice() {
+                    [constant] if constant.factors.is_empty() => {
+                        denominator *= constant.coeff.pow(-factor.power as u32);
+                        false
+                    }
+                    _ => true,
+                },
+                _ => true,
+            });
+            if denominator < 0 {
+                term.coeff = -term.coeff;
+                denominator = -denominator;
+            }
+            terms.push((term, denominator));
+        }
+        let denominator = terms.iter().fold(1, |lcm, (_, d)| lcm / gcd(lcm, *d) * d);
+        let terms = terms
+            .into_iter()
+            .map(|(term, d)| Mono {
+                coeff: term.coeff * (denominator / d),
+                factors: term.factors,
+            })
+            .collect();
+        Ok(Derivative {
+            numerator: Poly { terms },
+            denominator,
+        })
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Function of one argument described by its properties.
+#[derive(Debug, Clone)]
+pub struct UnaryFunction {
+    pub name: Cow<'static, str>,
+    pub eval: fn(f64) -> f64,
+    pub derivative: Option<Cow<'static, str>>,
+    pub parity: Parity,
+    pub special_values: Vec<(i32, i32)>,
+    parsed: OnceLock<Option<Derivative>>,
+}
+
+impl UnaryFunction {
+    pub fn new<S: Into<Cow<'static, str>>>(name: S, eval: fn(f64) -> f64) -> Self {
+        UnaryFunction {
+            name: name.into(),
+            eval,
+            derivative: None,
+            parity: Parity::None,
+            special_values: vec![],
+            parsed: OnceLock::new(),
+        }
+    }
+
+    /// Set the derivative, an expression of `x` parsed by `Derivative::parse` on first use,
+    /// once the functions it refers to are registered.
+    pub fn with_derivative<S: Into<Cow<'static, str>>>(mut self, derivative: S) -> Self {
+        self.derivative = Some(derivative.into());
+        self
+    }
+
+    pub fn with_parity(mut self, parity: Parity) -> Self {
+        self.parity = parity;
+        self
+    }
+
+    pub fn with_special_value(mut self, arg: i32, value: i32) -> Self {
+        self.special_values.push((arg, value));
+        self
+    }
+}
+
+impl Function for UnaryFunction {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn eval(&self, args: &[f64]) -> f64 {
+        (self.eval)(args[0])
+    }
+
+    fn derivative(&self) -> Option<&Derivative> {
+        self.parsed
+            .get_or_init(|| Derivative::parse(self.derivative.as_deref()?).ok())
+            .as_ref()
+    }
+
+    fn parity(&self) -> Parity {
+        self.parity
+    }
+
+    fn special_values(&self) -> &[(i32, i32)] {
+        &self.special_values
+    }
+}
+
+fn builtin_functions() -> Vec<UnaryFunction> {
+    vec![
+        UnaryFunction::new("sin", f64::sin)
+            .with_derivative("cos(x)")
+            .with_parity(Parity::Odd)
+            .with_special_value(0, 0),
+        UnaryFunction::new("cos", f64::cos)
+            .with_derivative("- sin(x)")
+            .with_parity(Parity::Even)
+            .with_special_value(0, 1),
+        UnaryFunction::new("tan", f64::tan)
+            .with_derivative("cos(x)^-2")
+            .with_parity(Parity::Odd)
+            .with_special_value(0, 0),
+        UnaryFunction::new("exp", f64::exp)
+            .with_derivative("exp(x)")
+            .with_special_value(0, 1),
+        UnaryFunction::new("ln", f64::ln)
+            .with_derivative("x^-1")
+            .with_special_value(1, 0),
+        UnaryFunction::new("sqrt", f64::sqrt)
+            .with_derivative("(2)^-1 sqrt(x)^-1")
+            .with_special_value(0, 0)
+            .with_special_value(1, 1),
+    ]
+}
+
+type Registry = HashMap<String, Arc<dyn Function>>;
+
+static REGISTRY: LazyLock<RwLock<Registry>> = LazyLock::new(|| {
+    let mut registry: Registry = HashMap::new();
+    for function in builtin_functions() {
+        registry.insert(function.name().to_string(), Arc::new(function));
+    }
+    RwLock::new(registry)
+});
+
+/// Register a function, replacing any function of the same name.
+pub fn register_function<F: Function + 'static>(function: F) {
+    let mut registry = REGISTRY.write().unwrap_or_else(|error| error.into_inner());
+    registry.insert(function.name().to_string(), Arc::new(function));
+}
+
+/// Registered function of the given name, `sin`, `cos`, `tan`, `exp`, `ln` and `sqrt` being built in.
+pub fn lookup_function(name: &str) -> Option<Arc<dyn Function>> {
+    let registry = REGISTRY.read().unwrap_or_else(|error| error.into_inner());
+    registry.get(name).cloned()
+}
+
+/// Names of the registered functions.
+pub(crate) fn function_names() -> Vec<String> {
+    let registry = REGISTRY.read().unwrap_or_else(|error| error.into_inner());
+    registry.keys().cloned().collect()
+}
+
+/// Coefficient equal to the integer `n`.
+pub(crate) fn coeff_of<T: FromPrimitive>(n: i64) -> T {
+    T::from_i64(n).expect("integer out of range of the coefficients")
+}
+
+/// Replace the symbol `var` by `value` in the integer polynomial `poly`.
+fn instantiate<T: Coeff + FromPrimitive>(poly: &Poly<i64>, var: &Sym, value: &Poly<T>) -> Poly<T> {
+    let base = |base: &Base<i64>| match base {
+        Base::Sym(sym) if sym == var => match value.terms.as_slice() {
+            [term] if term.is_symbol() => term.factors[0].base.clone(),
+            _ => Base::Poly(value.clone()),
+        },
+        Base::Sym(sym) => Base::Sym(sym.clone()),
+        Base::Der(der) => Base::Der(Der {
+            func: der.func.clone(),
+            param: instantiate(&der.param, var, value),
+        }),
+        Base::Poly(poly) => Base::Poly(instantiate(poly, var, value)),
+    };
+    let mut terms = vec![];
+    for term in &poly.terms {
+        match term.factors.as_slice() {
+            // a plain `x` term is replaced by the terms of `value`
+            [factor] if factor.base == Base::Sym(var.clone()) && factor.power == 1 => {
+                terms.extend(value.terms.iter().map(|inner| Mono {
+                    coeff: coeff_of::<T>(term.coeff) * inner.coeff.clone(),
+                    factors: inner.factors.clone(),
+                }))
+            }
+            factors => terms.push(Mono {
+                coeff: coeff_of(term.coeff),
+                factors: factors
+                    .iter()
+                    .map(|factor| Factor {
+                        base: base(&factor.base),
+                        power: factor.power,
+                    })
+                    .collect(),
+            }),
+        }
+    }
+    Poly { terms }
+}
+
+/// Derivative of the registered function `func` at `param` and its denominator, if known.
+pub(crate) fn derivative_at<T: Coeff + FromPrimitive>(
+    func: &str,
+    param: &Poly<T>,
+) -> Option<(Poly<T>, T)> {
+    let function = lookup_function(func)?;
+    let derivative = function.derivative()?;
+    let numerator = instantiate(&derivative.numerator, &Sym::from("x"), param);
+    Some((numerator, coeff_of(derivative.denominator)))
+}
+
+impl<T: Coeff + FromPrimitive> Poly<T> {
+    /// Apply the function `func` to this polynomial.
+    ///
+    /// For registered functions special values are evaluated and, by parity,
+    /// a negative leading term is taken out of the argument, e.g. `sin(-x) = -sin x`.
+    pub fn apply<S: Into<Cow<'static, str>>>(&self, func: S) -> Poly<T> {
+        let func = func.into();
+        let mut param = self.clone();
+        let mut coeff = T::one();
+        if let Some(function) = lookup_function(&func) {
+            if param.terms.iter().all(|term| term.factors.is_empty()) {
+                let value = param
+                    .terms
+                    .iter()
+                    .fold(T::zero(), |sum, term| sum + term.coeff.clone());
+                let special = function
+                    .special_values()
+                    .iter()
+                    .find(|(arg, _)| coeff_of::<T>(*arg as i64) == value);
+                if let Some((_, value)) = special {
+                    return match value {
+                        0 => Poly { terms: vec![] },
+                        _ => Poly::from(Mono {
+                            coeff: coeff_of(*value as i64),
+                            factors: vec![],
+                        }),
+                    };
+                }
+            }
+            let leading = param.terms.iter().max_by(|a, b| a.factors.cmp(&b.factors));
+            let negative = leading.is_some_and(|term| term.coeff.is_negative());
+            if negative && function.parity() != Parity::None {
+                param = -param;
+                if function.parity() == Parity::Odd {
+                    coeff = -coeff;
+                }
+            }
+        }
+        Poly::from(Mono {
+            coeff,
+            factors: vec![Factor {
+                base: Base::Der(Der { func, param }),
+                power: 1,
+            }],
+        })
+    }
+}
@@ -0,0 +1,183 @@
+use crate::hensel::{choose_point, evaluate, lift};
+use crate::mpoly::{coeff_gcd, MPoly};
+use crate::upoly::{self, Rng};
+use crate::{IntegerCoeff, Poly};
+
+impl<T: IntegerCoeff> Poly<T> {
+    /// Greatest common divisor over the integers, with positive leading coefficient.
+    ///
+    /// Symbols, derivatives and non-expandable factors are treated as variables,
+    /// nested polynomials are expanded. The computation runs over `BigInt`, so only a gcd
+    /// with coefficients out of range of `T` is lost, replaced by the gcd of the contents.
+    pub fn gcd(&self, other: &Poly<T>) -> Poly<T> {
+        let mut vars = vec![];
+        let mut a = MPoly::from_poly(self, &mut vars);
+        let mut b = MPoly::from_poly(other, &mut vars);
+        a.resize(vars.len());
+        b.resize(vars.len());
+        match MPoly::from_bigint(&gcd(&a.to_bigint(), &b.to_bigint())) {
+            Some(g) => g.to_poly(&vars),
+            None => MPoly::constant(a.nvars, coeff_gcd(&a.content(), &b.content())).to_poly(&vars),
+        }
+    }
+}
+
+/// Greatest common divisor over the integers, normalized to a positive leading coefficient.
+pub(crate) fn gcd<T: IntegerCoeff>(a: &MPoly<T>, b: &MPoly<T>) -> MPoly<T> {
+    if a.is_zero() {
+        return b.clone().normalize();
+    }
+    if b.is_zero() {
+        return a.clone().normalize();
+    }
+    let (ma, mb) = (a.min_exponents(), b.min_exponents());
+    let monomial = ma
+        .iter()
+        .zip(&mb)
+        .map(|(x, y)| -(*x).min(*y))
+        .collect::<Vec<_>>();
+    let (ca, cb) = (a.content(), b.content());
+    let a = a.shift(&ma).div_coeff(ca.clone());
+    let b = b.shift(&mb).div_coeff(cb.clone());
+    gcd_primitive(&a, &b)
+        .mul_coeff(coeff_gcd(&ca, &cb))
+        .shift(&monomial)
+}
+
+/// Greatest common divisor of the coefficients with respect to `x`.
+pub(crate) fn content_in<T: IntegerCoeff>(f: &MPoly<T>, x: usize) -> MPoly<T> {
+    let mut coeffs = f
+        .coeffs_in(x)
+        .into_iter()
+        .map(|(_, coeff)| coeff)
+        .collect::<Vec<_>>();
+    coeffs.sort_by_key(|coeff| coeff.terms.len());
+    let mut result = coeffs.remove(0).normalize();
+    for coeff in coeffs {
+        if result.is_constant() {
+            break;
+        }
+        result = gcd(&result, &coeff);
+    }
+    if result.is_constant() {
+        let content = f.content().abs();
+        return MPoly::constant(f.nvars, content);
+    }
+    result
+}
+
+/// Both polynomials are free of integer and monomial content.
+fn gcd_primitive<T: IntegerCoeff>(a: &MPoly<T>, b: &MPoly<T>) -> MPoly<T> {
+    let one = MPoly::constant(a.nvars, T::one());
+    let (va, vb) = (a.variables(), b.variables());
+    if va.is_empty() || vb.is_empty() {
+        return one;
+    }
+    if let Some(&x) = va.iter().find(|x| !vb.contains(x)) {
+        return gcd(&content_in(a, x), b);
+    }
+    if let Some(&x) = vb.iter().find(|x| !va.contains(x)) {
+        return gcd(a, &content_in(b, x));
+    }
+    if let [x] = va[..] {
+        let dense = |f: &MPoly<T>| {
+            f.to_dense(x)
+                .iter()
+                .map(IntegerCoeff::to_bigint)
+                .collect::<Vec<_>>()
+        };
+        let g = upoly::gcd(&dense(a), &dense(b))
+            .iter()
+            .map(T::from_bigint)
+            .collect::<Option<Vec<_>>>();
+        if let Some(g) = g {
+            return MPoly::from_dense(a.nvars, x, &g);
+        }
+        // gcd coefficients out of range of `T`
+        return gcd_prs(a, b, x);
+    }
+    let x = *va
+        .iter()
+        .min_by_key(|&&x| a.degree(x).min(b.degree(x)))
+        .unwrap();
+    let (ca, cb) = (content_in(a, x), content_in(b, x));
+    let content = gcd(&ca, &cb);
+    let a = a.div_exact(&ca).unwrap();
+    let b = b.div_exact(&cb).unwrap();
+    gcd_in(&a, &b, x).mul(&content).normalize()
+}
+
+/// Both polynomials are primitive with respect to `x` and contain the same variables.
+fn gcd_in<T: IntegerCoeff>(a: &MPoly<T>, b: &MPoly<T>, x: usize) -> MPoly<T> {
+    let (a, b) = if a.degree(x) >= b.degree(x) {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let (la, lb) = (a.lc_in(x), b.lc_in(x));
+    let gamma = gcd(&la, &lb);
+    let vars = a
+        .variables()
+        .into_iter()
+        .filter(|v| *v != x)
+        .collect::<Vec<_>>();
+    let mut rng = Rng::new();
+    for attempt in 0..20 {
+        let point = choose_point(a.nvars, &vars, attempt, &mut rng);
+        let (ua, ub) = (evaluate(a, x, &point), evaluate(b, x, &point));
+        if ua.len() as i32 != a.degree(x) + 1 || ub.len() as i32 != b.degree(x) + 1 {
+            continue;
+        }
+        let g0 = upoly::gcd(&ua, &ub);
+        if g0.len() == 1 {
+            return MPoly::constant(a.nvars, T::one());
+        }
+        if g0.len() as i32 == b.degree(x) + 1 {
+            if a.div_exact(b).is_some() {
+                return b.clone().normalize();
+            }
+            continue;
+        }
+        for (f, uf, lf) in [(a, &ua, &la), (b, &ub, &lb)] {
+            let Some(h0) = upoly::div_exact(&upoly::primitive(uf), &g0) else {
+                continue;
+            };
+            if upoly::gcd(&g0, &h0).len() > 1 {
+                continue;
+            }
+            let Some(g) = lift(&f.mul(&gamma), x, &point, &g0, &h0, &gamma, lf) else {
+                continue;
+            };
+            let g = g.div_exact(&content_in(&g, x)).unwrap().normalize();
+            if a.div_exact(&g).is_some() && b.div_exact(&g).is_some() {
+                return g;
+            }
+        }
+    }
+    gcd_prs(a, b, x)
+}
+
+/// Primitive polynomial remainder sequence, used when no suitable evaluation point is found.
+fn gcd_prs<T: IntegerCoeff>(a: &MPoly<T>, b: &MPoly<T>, x: usize) -> MPoly<T> {
+    let mut a = a.clone();
+    let mut b = b.clone();
+    while !b.is_zero() && b.degree(x) > 0 {
+        let lb = b.lc_in(x);
+        let mut r = a;
+        while !r.is_zero() && r.degree(x) >= b.degree(x) {
+            let shift = r.degree(x) - b.degree(x);
+            r = r.mul(&lb).sub(&b.mul(&r.lc_in(x)).mul_var(x, shift));
+        }
+        a = b;
+        b = if r.is_zero() {
+            r
+        } else {
+            r.div_exact(&content_in(&r, x)).unwrap()
+        };
+    }
+    if b.is_zero() {
+        a.normalize()
+    } else {
+        MPoly::constant(a.nvars, T::one())
+    }
+}
@@ -0,0 +1,340 @@
+//! Gröbner bases of polynomial ideals over the rationals, computed with primitive integer
+//! polynomials so that no fractions appear.
+
+use crate::mpoly::{coeff_gcd, MPoly};
+use crate::{Poly, SignedCoeff, TermOrder};
+use num_traits::{Signed, Zero};
+use std::collections::BTreeSet;
+
+/// Algorithm used to compute a Gröbner basis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroebnerAlgorithm {
+    /// pair by pair reduction of S-polynomials
+    #[default]
+    Buchberger,
+    /// simultaneous reduction of the pairs of lowest degree by row echelon form
+    F4,
+}
+
+/// Reduced Gröbner basis, each polynomial primitive with positive leading coefficient.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroebnerBasis<T: SignedCoeff> {
+    pub polys: Vec<Poly<T>>,
+    pub order: TermOrder<T>,
+}
+
+impl<T: SignedCoeff> GroebnerBasis<T> {
+    /// Compute the reduced Gröbner basis of the ideal generated by `polys` with Buchberger's algorithm.
+    pub fn new(polys: &[Poly<T>], order: impl Into<TermOrder<T>>) -> Self {
+        Self::with_algorithm(polys, order, GroebnerAlgorithm::Buchberger)
+    }
+
+    pub fn with_algorithm(
+        polys: &[Poly<T>],
+        order: impl Into<TermOrder<T>>,
+        algorithm: GroebnerAlgorithm,
+    ) -> Self {
+        let order = order.into();
+        let (polys, vars) = MPoly::from_polys_in(&polys.iter().collect::<Vec<_>>(), &order.vars);
+        let order = &order;
+        let polys = polys
+            .into_iter()
+            .filter(|p| !p.is_zero())
+            .map(|p| primitive(p, order))
+            .collect::<Vec<_>>();
+        let basis = match algorithm {
+            GroebnerAlgorithm::Buchberger => buchberger(polys, order),
+            GroebnerAlgorithm::F4 => f4(polys, order),
+        };
+        GroebnerBasis {
+            polys: reduce_basis(basis, order)
+                .iter()
+                .map(|p| p.to_poly(&vars))
+                .collect(),
+            order: order.clone(),
+        }
+    }
+
+    /// Normal form of `f` modulo the ideal.
+    ///
+    /// Returns `(c, r)` with `c f - r` in the ideal, where no term of `r` is divisible by a leading
+    /// monomial of the basis. The multiplier `c` is positive and equals one when all leading
+    /// coefficients of the basis are one.
+    pub fn normal_form(&self, f: &Poly<T>) -> (T, Poly<T>) {
+        let mut polys = vec![f];
+        polys.extend(&self.polys);
+        let (polys, vars) = MPoly::from_polys_in(&polys, &self.order.vars);
+        let (multiplier, remainder) = reduce(&polys[0], &polys[1..], &self.order);
+        (multiplier, remainder.to_poly(&vars))
+    }
+
+    /// Whether `f` belongs to the ideal.
+    pub fn contains(&self, f: &Poly<T>) -> bool {
+        self.normal_form(f).1.is_zero()
+    }
+}
+
+type Monomial = Vec<i32>;
+
+fn leading<T: SignedCoeff>(f: &MPoly<T>, order: &TermOrder<T>) -> (Monomial, T) {
+    f.leading(order).cloned().expect("zero polynomial")
+}
+
+fn divides(a: &[i32], b: &[i32]) -> bool {
+    a.iter().zip(b).all(|(x, y)| x <= y)
+}
+
+fn lcm(a: &[i32], b: &[i32]) -> Monomial {
+    a.iter().zip(b).map(|(x, y)| *x.max(y)).collect()
+}
+
+fn quotient(a: &[i32], b: &[i32]) -> Monomial {
+    a.iter().zip(b).map(|(x, y)| x - y).collect()
+}
+
+fn monomial<T: SignedCoeff>(nvars: usize, exps: Monomial, coeff: T) -> MPoly<T> {
+    MPoly {
+        nvars,
+        terms: vec![(exps, coeff)],
+    }
+}
+
+/// Divide by the content, making the leading coefficient positive.
+fn primitive<T: SignedCoeff>(f: MPoly<T>, order: &TermOrder<T>) -> MPoly<T> {
+    let content = f.content().abs();
+    let (_, lc) = leading(&f, order);
+    f.div_coeff(if Signed::is_negative(&lc) {
+        -content
+    } else {
+        content
+    })
+}
+
+/// Fully reduce `f` by `divisors`, returning `(c, r)` where `c f - r` is a combination of the divisors.
+fn reduce<T: SignedCoeff>(
+    f: &MPoly<T>,
+    divisors: &[MPoly<T>],
+    order: &TermOrder<T>,
+) -> (T, MPoly<T>) {
+    let leads = divisors
+        .iter()
+        .filter(|g| !g.is_zero())
+        .map(|g| (leading(g, order), g))
+        .collect::<Vec<_>>();
+    let mut multiplier = T::one();
+    let mut remainder = MPoly::constant(f.nvars, T::zero());
+    let mut p = f.clone();
+    while let Some((exps, coeff)) = p.leading(order).cloned() {
+        let reducer = leads
+            .iter()
+            .find(|((lead_exps, _), _)| divides(lead_exps, &exps));
+        match reducer {
+            Some(((lead_exps, lead_coeff), g)) => {
+                // lead_coeff p - coeff m g cancels the leading term, after removing common factors
+                let gcd = coeff_gcd(&coeff, lead_coeff);
+                let (a, b) = (lead_coeff.clone() / gcd.clone(), coeff / gcd);
+                let (a, b) = if Signed::is_negative(&a) {
+                    (-a, -b)
+                } else {
+                    (a, b)
+                };
+                let m = monomial(f.nvars, quotient(&exps, lead_exps), b);
+                p = p.mul_coeff(a.clone()).sub(&m.mul(g));
+                remainder = remainder.mul_coeff(a.clone());
+                multiplier = multiplier * a;
+            }
+            None => {
+                let term = monomial(f.nvars, exps, coeff);
+                p = p.sub(&term);
+                remainder = remainder.add(&term);
+            }
+        }
+    }
+    (multiplier, remainder)
+}
+
+/// S-polynomial of `f` and `g`, cancelling their leading terms.
+fn s_poly<T: SignedCoeff>(f: &MPoly<T>, g: &MPoly<T>, order: &TermOrder<T>) -> MPoly<T> {
+    let (ef, cf) = leading(f, order);
+    let (eg, cg) = leading(g, order);
+    let l = lcm(&ef, &eg);
+    let gcd = coeff_gcd(&cf, &cg);
+    let mf = monomial(f.nvars, quotient(&l, &ef), cg / gcd.clone());
+    let mg = monomial(f.nvars, quotient(&l, &eg), cf / gcd);
+    mf.mul(f).sub(&mg.mul(g))
+}
+
+/// Product criterion: the S-polynomial reduces to zero when the leading monomials are coprime.
+fn coprime<T: SignedCoeff>(f: &MPoly<T>, g: &MPoly<T>, order: &TermOrder<T>) -> bool {
+    let (ef, _) = leading(f, order);
+    let (eg, _) = leading(g, order);
+    ef.iter().zip(&eg).all(|(x, y)| *x == 0 || *y == 0)
+}
+
+/// Add the pairs of a new basis element with the previous ones.
+fn add_pairs<T: SignedCoeff>(
+    pairs: &mut Vec<(usize, usize)>,
+    basis: &[MPoly<T>],
+    index: usize,
+    order: &TermOrder<T>,
+) {
+    for i in 0..index {
+        if !coprime(&basis[i], &basis[index], order) {
+            pairs.push((i, index));
+        }
+    }
+}
+
+fn pair_lcm<T: SignedCoeff>(
+    basis: &[MPoly<T>],
+    (i, j): (usize, usize),
+    order: &TermOrder<T>,
+) -> Monomial {
+    lcm(&leading(&basis[i], order).0, &leading(&basis[j], order).0)
+}
+
+fn buchberger<T: SignedCoeff>(mut basis: Vec<MPoly<T>>, order: &TermOrder<T>) -> Vec<MPoly<T>> {
+    let mut pairs = vec![];
+    for index in 0..basis.len() {
+        add_pairs(&mut pairs, &basis, index, order);
+    }
+    // normal strategy, the pair of the lowest lcm first
+    while let Some(position) = (0..pairs.len()).min_by(|a, b| {
+        order.compare(
+            &pair_lcm(&basis, pairs[*a], order),
+            &pair_lcm(&basis, pairs[*b], order),
+        )
+    }) {
+        let (i, j) = pairs.swap_remove(position);
+        let s = s_poly(&basis[i], &basis[j], order);
+        let (_, r) = reduce(&s, &basis, order);
+        if !r.is_zero() {
+            basis.push(primitive(r, order));
+            add_pairs(&mut pairs, &basis, basis.len() - 1, order);
+        }
+    }
+    basis
+}
+
+fn f4<T: SignedCoeff>(mut basis: Vec<MPoly<T>>, order: &TermOrder<T>) -> Vec<MPoly<T>> {
+    let mut pairs = vec![];
+    for index in 0..basis.len() {
+        add_pairs(&mut pairs, &basis, index, order);
+    }
+    while !pairs.is_empty() {
+        // all pairs of the lowest degree are reduced together
+        let degree = |pair| pair_lcm(&basis, pair, order).iter().sum::<i32>();
+        let lowest = pairs.iter().map(|pair| degree(*pair)).min().unwrap();
+        let (selected, rest): (Vec<_>, Vec<_>) =
+            pairs.into_iter().partition(|pair| degree(*pair) == lowest);
+        pairs = rest;
+
+        let mut rows = vec![];
+        for (i, j) in selected {
+            let l = pair_lcm(&basis, (i, j), order);
+            for g in [&basis[i], &basis[j]] {
+                let m = monomial(g.nvars, quotient(&l, &leading(g, order).0), T::one());
+                rows.push(m.mul(g));
+            }
+        }
+        // symbolic preprocessing, adding reducers for every monomial that can be reduced
+        let mut done = rows
+            .iter()
+            .map(|row| leading(row, order).0)
+            .collect::<BTreeSet<_>>();
+        let mut index = 0;
+        while index < rows.len() {
+            let monomials = rows[index]
+                .terms
+                .iter()
+                .map(|(exps, _)| exps.clone())
+                .collect::<Vec<_>>();
+            for exps in monomials {
+                if !done.insert(exps.clone()) {
+                    continue;
+                }
+                let reducer = basis.iter().find(|g| divides(&leading(g, order).0, &exps));
+                if let Some(g) = reducer {
+                    let m = monomial(g.nvars, quotient(&exps, &leading(g, order).0), T::one());
+                    rows.push(m.mul(g));
+                }
+            }
+            index += 1;
+        }
+
+        // the rows with new leading monomials extend the basis
+        let leads = rows
+            .iter()
+            .map(|row| leading(row, order).0)
+            .collect::<BTreeSet<_>>();
+        for row in echelon(rows, order) {
+            if !leads.contains(&leading(&row, order).0) {
+                basis.push(row);
+                add_pairs(&mut pairs, &basis, basis.len() - 1, order);
+            }
+        }
+    }
+    basis
+}
+
+/// Fraction-free row echelon form, the rows having pairwise distinct leading monomials.
+fn echelon<T: SignedCoeff>(mut rows: Vec<MPoly<T>>, order: &TermOrder<T>) -> Vec<MPoly<T>> {
+    let mut result = vec![];
+    loop {
+        rows.retain(|row| !row.is_zero());
+        let Some(position) = (0..rows.len()).max_by(|a, b| {
+            order.compare(&leading(&rows[*a], order).0, &leading(&rows[*b], order).0)
+        }) else {
+            break;
+        };
+        let pivot = primitive(rows.swap_remove(position), order);
+        let (lead_exps, lead_coeff) = leading(&pivot, order);
+        for row in &mut rows {
+            let (exps, coeff) = leading(row, order);
+            if exps == lead_exps {
+                let gcd = coeff_gcd(&coeff, &lead_coeff);
+                let reduced = row
+                    .mul_coeff(lead_coeff.clone() / gcd.clone())
+                    .sub(&pivot.mul_coeff(coeff / gcd));
+                *row = if reduced.is_zero() {
+                    reduced
+                } else {
+                    primitive(reduced, order)
+                };
+            }
+        }
+        result.push(pivot);
+    }
+    result
+}
+
+/// Make the basis reduced: minimal leading monomials and no term divisible by another leading monomial.
+fn reduce_basis<T: SignedCoeff>(basis: Vec<MPoly<T>>, order: &TermOrder<T>) -> Vec<MPoly<T>> {
+    let leads = basis
+        .iter()
+        .map(|g| leading(g, order).0)
+        .collect::<Vec<_>>();
+    let minimal = basis
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| {
+            !leads.iter().enumerate().any(|(j, lead)| {
+                j != *i && divides(lead, &leads[*i]) && (*lead != leads[*i] || j < *i)
+            })
+        })
+        .map(|(_, g)| g)
+        .collect::<Vec<_>>();
+    let mut result = (0..minimal.len())
+        .map(|i| {
+            let others = minimal
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, g)| g.clone())
+                .collect::<Vec<_>>();
+            primitive(reduce(&minimal[i], &others, order).1, order)
+        })
+        .collect::<Vec<_>>();
+    result.sort_by(|a, b| order.compare(&leading(a, order).0, &leading(b, order).0));
+    result
+}
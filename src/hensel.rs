@@ -0,0 +1,252 @@
+//! Multivariate Hensel lifting modulo a large prime, shared by the gcd and the
+//! factorization algorithms.
+
+use crate::mpoly::MPoly;
+use crate::upoly::{self, Rng};
+use crate::IntegerCoeff;
+use num_bigint::BigInt;
+use num_traits::Zero;
+use std::collections::BTreeMap;
+
+/// Mersenne prime `2^61 - 1`.
+const P: u64 = (1 << 61) - 1;
+
+/// Lifted coefficients are recovered exactly when they have at most this many bits.
+pub(crate) const EXACT_BITS: u64 = 60;
+
+/// Polynomial in the main variable modulo `P`, keyed by the exponents of the other variables.
+type Lifted = BTreeMap<Vec<i32>, Vec<u64>>;
+
+fn to_mod(c: i128) -> u64 {
+    c.rem_euclid(P as i128) as u64
+}
+
+fn from_mpoly<T: IntegerCoeff>(f: &MPoly<T>, x: usize) -> Lifted {
+    let mut result = Lifted::new();
+    for (exps, coeff) in &f.terms {
+        let mut exps = exps.clone();
+        let power = std::mem::replace(&mut exps[x], 0) as usize;
+        let poly = result.entry(exps).or_default();
+        if poly.len() <= power {
+            poly.resize(power + 1, 0);
+        }
+        poly[power] = upoly::to_mod(&coeff.to_bigint(), P);
+    }
+    result
+}
+
+fn add_to(map: &mut Lifted, key: Vec<i32>, poly: &[u64]) {
+    let entry = map.entry(key).or_default();
+    *entry = upoly::fp_add(entry, poly, P);
+}
+
+/// Substitute `y_i + point[i]` for every variable `y_i`.
+fn shift(f: Lifted, point: &[i128]) -> Lifted {
+    let mut f = f;
+    for (var, value) in point.iter().enumerate() {
+        if *value == 0 {
+            continue;
+        }
+        let value = to_mod(*value);
+        let mut result = Lifted::new();
+        for (exps, poly) in f {
+            // (y + a)^e = sum of C(e, j) a^(e - j) y^j
+            let e = exps[var];
+            let mut coeff = 1;
+            for j in (0..=e).rev() {
+                let mut key = exps.clone();
+                key[var] = j;
+                let term = poly
+                    .iter()
+                    .map(|c| upoly::mul_p(*c, coeff, P))
+                    .collect::<Vec<_>>();
+                add_to(&mut result, key, &term);
+                // C(e, j - 1) a^(e - j + 1) = C(e, j) a^(e - j) * a * j / (e - j + 1)
+                coeff = upoly::mul_p(coeff, value, P);
+                coeff = upoly::mul_p(coeff, j as u64, P);
+                coeff = upoly::mul_p(coeff, upoly::inv_mod((e - j + 1) as u64, P), P);
+            }
+        }
+        result.retain(|_, poly| !poly.is_empty());
+        f = result;
+    }
+    f
+}
+
+fn total_degree(exps: &[i32]) -> usize {
+    exps.iter().sum::<i32>() as usize
+}
+
+/// Split into homogeneous parts by total degree in the other variables.
+fn by_degree(f: Lifted) -> Vec<Lifted> {
+    let mut parts = vec![];
+    for (exps, poly) in f {
+        let degree = total_degree(&exps);
+        if parts.len() <= degree {
+            parts.resize(degree + 1, Lifted::new());
+        }
+        parts[degree].insert(exps, poly);
+    }
+    parts
+}
+
+/// Sum of the products of the parts of `g` and `h` whose degrees add up to `k`.
+fn product_part(g: &[Lifted], h: &[Lifted], k: usize) -> Lifted {
+    let mut result = Lifted::new();
+    for i in 0..=k.min(g.len().saturating_sub(1)) {
+        if k - i >= h.len() {
+            continue;
+        }
+        for (mg, pg) in &g[i] {
+            for (mh, ph) in &h[k - i] {
+                let key = mg.iter().zip(mh).map(|(a, b)| a + b).collect();
+                add_to(&mut result, key, &upoly::fp_mul(pg, ph, P));
+            }
+        }
+    }
+    result.retain(|_, poly| !poly.is_empty());
+    result
+}
+
+/// Replace the leading coefficient of the univariate image `u` by the polynomial `lc`.
+fn with_leading(u: &[BigInt], lc: &Lifted) -> Option<Vec<Lifted>> {
+    let mut u = upoly::reduce(u, P);
+    let zero = vec![0; lc.keys().next()?.len()];
+    let lc0 = lc.get(&zero)?.first().copied()?;
+    let inv = upoly::inv_mod(*u.last()?, P);
+    for c in u.iter_mut() {
+        *c = upoly::mul_p(upoly::mul_p(*c, inv, P), lc0, P);
+    }
+    let degree = u.len() - 1;
+    let mut result = Lifted::new();
+    for (exps, c) in lc {
+        if *exps != zero {
+            let mut poly = vec![0; degree + 1];
+            poly[degree] = c[0];
+            result.insert(exps.clone(), poly);
+        }
+    }
+    result.insert(zero, u);
+    Some(by_degree(result))
+}
+
+/// Lift the factorization `f(x, point) = g0 h0` to a factorization `f = g h` whose factors
+/// have the leading coefficients `lg` and `lh` with respect to `x`.
+///
+/// `g0` and `h0` must be coprime and `lg lh` must equal the leading coefficient of `f`.
+/// Returns `g` when the lifted factors multiply to `f`.
+pub(crate) fn lift<T: IntegerCoeff>(
+    f: &MPoly<T>,
+    x: usize,
+    point: &[i128],
+    g0: &[BigInt],
+    h0: &[BigInt],
+    lg: &MPoly<T>,
+    lh: &MPoly<T>,
+) -> Option<MPoly<T>> {
+    let target = by_degree(shift(from_mpoly(f, x), point));
+    let mut g = with_leading(g0, &shift(from_mpoly(lg, x), point))?;
+    let mut h = with_leading(h0, &shift(from_mpoly(lh, x), point))?;
+    let zero = vec![0; f.nvars];
+    let g0 = g[0].get(&zero)?.clone();
+    let h0 = h[0].get(&zero)?.clone();
+    if upoly::fp_gcd(&g0, &h0, P).len() != 1 {
+        return None;
+    }
+    let (_, t) = upoly::fp_xgcd(&g0, &h0, P);
+
+    let max_degree = target.len() - 1;
+    g.resize(max_degree + 1, Lifted::new());
+    h.resize(max_degree + 1, Lifted::new());
+    for k in 1..=max_degree {
+        let mut error = target[k].clone();
+        for (exps, poly) in product_part(&g, &h, k) {
+            let entry = error.entry(exps).or_default();
+            *entry = upoly::fp_sub(entry, &poly, P);
+        }
+        for (exps, e) in error {
+            if e.is_empty() {
+                continue;
+            }
+            // e = (e s) g0 + (e t) h0, reduce e t modulo g0 to keep the degree of g
+            let sigma = upoly::fp_divrem(&upoly::fp_mul(&e, &t, P), &g0, P).1;
+            let (tau, r) = upoly::fp_divrem(
+                &upoly::fp_sub(&e, &upoly::fp_mul(&sigma, &h0, P), P),
+                &g0,
+                P,
+            );
+            if !r.is_empty() {
+                return None;
+            }
+            add_to(&mut g[k], exps.clone(), &sigma);
+            add_to(&mut h[k], exps, &tau);
+        }
+    }
+    for k in max_degree + 1..2 * max_degree + 1 {
+        if !product_part(&g, &h, k).is_empty() {
+            return None;
+        }
+    }
+
+    let mut lifted = Lifted::new();
+    for part in g {
+        lifted.extend(part.into_iter().filter(|(_, poly)| !poly.is_empty()));
+    }
+    let negated = point.iter().map(|c| -c).collect::<Vec<_>>();
+    let mut terms = BTreeMap::new();
+    for (exps, poly) in shift(lifted, &negated) {
+        for (power, c) in poly.iter().enumerate() {
+            if *c != 0 {
+                let c = if *c > P / 2 {
+                    -((P - c) as i128)
+                } else {
+                    *c as i128
+                };
+                let mut exps = exps.clone();
+                exps[x] = power as i32;
+                terms.insert(exps, T::from_bigint(&BigInt::from(c))?);
+            }
+        }
+    }
+    Some(MPoly {
+        nvars: f.nvars,
+        terms: terms.into_iter().rev().collect(),
+    })
+}
+
+/// Univariate image in `x` after substituting `point` for the other variables.
+pub(crate) fn evaluate<T: IntegerCoeff>(f: &MPoly<T>, x: usize, point: &[i128]) -> Vec<BigInt> {
+    let mut result = vec![BigInt::zero(); f.degree(x) as usize + 1];
+    for (exps, coeff) in &f.terms {
+        let mut value = coeff.to_bigint();
+        for (var, power) in exps.iter().enumerate() {
+            if var != x {
+                value *= BigInt::from(point[var]).pow(*power as u32);
+            }
+        }
+        result[exps[x] as usize] += value;
+    }
+    upoly::trim(&mut result);
+    result
+}
+
+/// Evaluation point for the variables `vars`, the first attempt is the origin,
+/// later attempts use random values growing slowly in size, leaving some variables at zero.
+pub(crate) fn choose_point(
+    nvars: usize,
+    vars: &[usize],
+    attempt: usize,
+    rng: &mut Rng,
+) -> Vec<i128> {
+    let mut point = vec![0; nvars];
+    if attempt == 0 {
+        return point;
+    }
+    let range = 2 + attempt as u64 / 4;
+    for &var in vars {
+        if attempt > 8 || rng.next().is_multiple_of(2) {
+            point[var] = (rng.next() % (2 * range + 1)) as i128 - range as i128;
+        }
+    }
+    point
+}
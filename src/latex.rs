@@ -0,0 +1,370 @@
+use crate::poly::abs;
+use crate::{Base, Coeff, Factor, Matrix, Mono, Poly, Quat, Sym, Vector, Zp, F64};
+use std::fmt;
+
+/// Conversion to LaTeX math mode markup.
+pub trait ToLatex {
+    /// Append the markup, breaking sums after every `terms_per_line` terms when given.
+    fn write_latex(&self, out: &mut String, terms_per_line: Option<usize>);
+
+    /// Whether the markup needs parentheses when multiplied by something else.
+    fn is_compound(&self) -> bool {
+        false
+    }
+
+    fn to_latex(&self) -> String {
+        let mut out = String::new();
+        self.write_latex(&mut out, None);
+        out
+    }
+
+    fn latex(&self) -> Latex<'_, Self> {
+        Latex {
+            value: self,
+            terms_per_line: None,
+        }
+    }
+}
+
+/// Wrapper displaying a value as LaTeX.
+#[derive(Debug, Clone, Copy)]
+pub struct Latex<'a, X: ?Sized> {
+    pub value: &'a X,
+    pub terms_per_line: Option<usize>,
+}
+
+impl<X: ?Sized> Latex<'_, X> {
+    /// Break long sums into lines of `terms` terms inside an `aligned` environment.
+    pub fn break_lines(mut self, terms: usize) -> Self {
+        self.terms_per_line = Some(terms.max(1));
+        self
+    }
+}
+
+impl<X: ToLatex + ?Sized> fmt::Display for Latex<'_, X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        self.value.write_latex(&mut out, self.terms_per_line);
+        write!(f, "{}", out)
+    }
+}
+
+macro_rules! to_latex_impl(
+    ($($t:ty),*) => {
+        $(
+            impl ToLatex for $t {
+                fn write_latex(&self, out: &mut String, _: Option<usize>) {
+                    out.push_str(&self.to_string());
+                }
+
+                fn is_compound(&self) -> bool {
+                    *self < (0 as $t)
+                }
+            }
+        )*
+    }
+);
+
+to_latex_impl!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl ToLatex for F64 {
+    fn write_latex(&self, out: &mut String, terms_per_line: Option<usize>) {
+        self.0.write_latex(out, terms_per_line);
+    }
+
+    fn is_compound(&self) -> bool {
+        self.0.is_compound()
+    }
+}
+
+impl ToLatex for crate::BigInt {
+    fn write_latex(&self, out: &mut String, _: Option<usize>) {
+        out.push_str(&self.to_string());
+    }
+
+    fn is_compound(&self) -> bool {
+        num_traits::Signed::is_negative(self)
+    }
+}
+
+/// Fractions as `\frac{1}{2}`, the sign in front.
+#[cfg(feature = "bigint")]
+impl ToLatex for crate::BigRational {
+    fn write_latex(&self, out: &mut String, _: Option<usize>) {
+        if self.is_integer() {
+            out.push_str(&self.numer().to_string());
+            return;
+        }
+        if self.is_compound() {
+            out.push('-');
+        }
+        let numer = num_traits::Signed::abs(self.numer());
+        out.push_str(&format!("\\frac{{{}}}{{{}}}", numer, self.denom()));
+    }
+
+    fn is_compound(&self) -> bool {
+        num_traits::Signed::is_negative(self)
+    }
+}
+
+impl<const P: u64> ToLatex for Zp<P> {
+    fn write_latex(&self, out: &mut String, _: Option<usize>) {
+        out.push_str(&self.to_string());
+    }
+}
+
+const GREEK: &[(char, &str)] = &[
+    ('α', "alpha"),
+    ('β', "beta"),
+    ('γ', "gamma"),
+    ('δ', "delta"),
+    ('ε', "epsilon"),
+    ('ζ', "zeta"),
+    ('η', "eta"),
+    ('θ', "theta"),
+    ('ι', "iota"),
+    ('κ', "kappa"),
+    ('λ', "lambda"),
+    ('μ', "mu"),
+    ('ν', "nu"),
+    ('ξ', "xi"),
+    ('π', "pi"),
+    ('ρ', "rho"),
+    ('σ', "sigma"),
+    ('τ', "tau"),
+    ('υ', "upsilon"),
+    ('φ', "phi"),
+    ('χ', "chi"),
+    ('ψ', "psi"),
+    ('ω', "omega"),
+    ('ϕ', "varphi"),
+    ('Γ', "Gamma"),
+    ('Δ', "Delta"),
+    ('Θ', "Theta"),
+    ('Λ', "Lambda"),
+    ('Ξ', "Xi"),
+    ('Π', "Pi"),
+    ('Σ', "Sigma"),
+    ('Υ', "Upsilon"),
+    ('Φ', "Phi"),
+    ('Ψ', "Psi"),
+    ('Ω', "Omega"),
+];
+
+/// Functions with a LaTeX command of their own.
+const FUNCTIONS: &[&str] = &[
+    "sin", "cos", "tan", "cot", "sec", "csc", "sinh", "cosh", "tanh", "arcsin", "arccos", "arctan",
+    "exp", "ln", "log",
+];
+
+/// Symbol name with greek letters as commands, spelled out or unicode, and `_` as subscript.
+fn write_name(out: &mut String, name: &str) {
+    let (main, subscript) = match name.split_once('_') {
+        Some((main, subscript)) if !main.is_empty() && !subscript.is_empty() => {
+            (main, Some(subscript))
+        }
+        _ => (name, None),
+    };
+    if GREEK.iter().any(|(_, word)| *word == main) {
+        out.push('\\');
+        out.push_str(main);
+    } else {
+        let mut command = false;
+        for c in main.chars() {
+            match GREEK.iter().find(|(letter, _)| *letter == c) {
+                Some((_, word)) => {
+                    out.push('\\');
+                    out.push_str(word);
+                    command = true;
+                }
+                None => {
+                    // separate a command from the letters following it
+                    if command && c.is_alphabetic() {
+                        out.push(' ');
+                    }
+                    out.push(c);
+                    command = false;
+                }
+            }
+        }
+    }
+    if let Some(subscript) = subscript {
+        out.push_str("_{");
+        write_name(out, subscript);
+        out.push('}');
+    }
+}
+
+impl ToLatex for Sym {
+    fn write_latex(&self, out: &mut String, _: Option<usize>) {
+        write_name(out, &self.0);
+    }
+}
+
+fn write_parenthesized<T: Coeff + ToLatex>(out: &mut String, poly: &Poly<T>) {
+    out.push_str("\\left(");
+    poly.write_latex(out, None);
+    out.push_str("\\right)");
+}
+
+fn write_power(out: &mut String, power: i32) {
+    if power != 1 {
+        out.push_str(&format!("^{{{}}}", power));
+    }
+}
+
+impl<T: Coeff + ToLatex> ToLatex for Factor<T> {
+    fn write_latex(&self, out: &mut String, _: Option<usize>) {
+        match &self.base {
+            Base::Sym(sym) => {
+                sym.write_latex(out, None);
+                write_power(out, self.power);
+            }
+            Base::Der(der) if der.func == "sqrt" => {
+                out.push_str("\\sqrt{");
+                der.param.write_latex(out, None);
+                out.push('}');
+                write_power(out, self.power);
+            }
+            Base::Der(der) => {
+                if FUNCTIONS.contains(&der.func.as_ref()) {
+                    out.push('\\');
+                    out.push_str(&der.func);
+                } else {
+                    out.push_str("\\operatorname{");
+                    write_name(out, &der.func);
+                    out.push('}');
+                }
+                write_power(out, self.power);
+                if der.param.is_symbol() {
+                    out.push(' ');
+                    der.param.write_latex(out, None);
+                } else {
+                    write_parenthesized(out, &der.param);
+                }
+            }
+            Base::Poly(poly) => {
+                if poly.is_symbol() {
+                    poly.write_latex(out, None);
+                } else {
+                    write_parenthesized(out, poly);
+                }
+                write_power(out, self.power);
+            }
+        }
+    }
+}
+
+fn write_mono<T: Coeff + ToLatex>(out: &mut String, term: &Mono<T>) {
+    let coeff = abs(&term.coeff);
+    let mut first = true;
+    if !coeff.is_one() || term.factors.is_empty() {
+        coeff.write_latex(out, None);
+        first = false;
+    }
+    for factor in &term.factors {
+        if !first {
+            out.push(' ');
+        }
+        first = false;
+        factor.write_latex(out, None);
+    }
+}
+
+impl<T: Coeff + ToLatex> ToLatex for Poly<T> {
+    fn write_latex(&self, out: &mut String, terms_per_line: Option<usize>) {
+        if self.terms.is_empty() {
+            out.push('0');
+            return;
+        }
+        let lines = terms_per_line.filter(|terms| self.terms.len() > *terms);
+        if lines.is_some() {
+            out.push_str("\\begin{aligned}\n& ");
+        }
+        for (index, term) in self.terms.iter().enumerate() {
+            if index == 0 {
+                if term.coeff.is_negative() {
+                    out.push('-');
+                }
+            } else {
+                if lines.is_some_and(|terms| index % terms == 0) {
+                    out.push_str(" \\\\\n&");
+                }
+                out.push_str(if term.coeff.is_negative() {
+                    " - "
+                } else {
+                    " + "
+                });
+            }
+            write_mono(out, term);
+        }
+        if lines.is_some() {
+            out.push_str("\n\\end{aligned}");
+        }
+    }
+
+    fn is_compound(&self) -> bool {
+        self.terms.len() > 1
+            || self
+                .terms
+                .first()
+                .is_some_and(|term| term.coeff.is_negative())
+    }
+}
+
+impl<T: ToLatex, const R: usize, const C: usize> ToLatex for Matrix<T, R, C> {
+    fn write_latex(&self, out: &mut String, terms_per_line: Option<usize>) {
+        out.push_str("\\begin{bmatrix}\n");
+        for r in 0..R {
+            for c in 0..C {
+                if c > 0 {
+                    out.push_str(" & ");
+                }
+                self.data[c][r].write_latex(out, terms_per_line);
+            }
+            if r + 1 < R {
+                out.push_str(" \\\\");
+            }
+            out.push('\n');
+        }
+        out.push_str("\\end{bmatrix}");
+    }
+}
+
+/// Column vector.
+impl<T: ToLatex, const D: usize> ToLatex for Vector<T, D> {
+    fn write_latex(&self, out: &mut String, terms_per_line: Option<usize>) {
+        out.push_str("\\begin{bmatrix}\n");
+        for (index, value) in self.data.iter().enumerate() {
+            value.write_latex(out, terms_per_line);
+            if index + 1 < D {
+                out.push_str(" \\\\");
+            }
+            out.push('\n');
+        }
+        out.push_str("\\end{bmatrix}");
+    }
+}
+
+/// Written as `q_0 + q_1 \mathbf{i} + q_2 \mathbf{j} + q_3 \mathbf{k}`.
+impl<T: ToLatex + Clone> ToLatex for Quat<T> {
+    fn write_latex(&self, out: &mut String, terms_per_line: Option<usize>) {
+        let data = &self.as_vector().data;
+        data[0].write_latex(out, terms_per_line);
+        for (value, unit) in data[1..].iter().zip(["i", "j", "k"]) {
+            out.push_str(" + ");
+            if value.is_compound() {
+                out.push_str("\\left(");
+                value.write_latex(out, None);
+                out.push_str("\\right)");
+            } else {
+                value.write_latex(out, None);
+            }
+            out.push_str(&format!(" \\mathbf{{{}}}", unit));
+        }
+    }
+
+    fn is_compound(&self) -> bool {
+        true
+    }
+}
@@ -1,10 +1,47 @@
 // #![feature(generic_const_exprs)]
+mod charpoly;
+mod checked;
+mod diff;
+mod division;
+mod eval;
+mod factor;
+mod float;
+mod function;
+mod gcd;
+mod groebner;
+mod hensel;
+mod latex;
 mod matrix;
+mod mpoly;
+mod order;
+mod parse;
 mod poly;
 mod quat;
+mod rational;
+mod resultant;
+mod solve;
+mod subs;
+mod upoly;
 mod vector;
+mod zp;
 
+pub use charpoly::*;
+pub use checked::*;
+pub use eval::*;
+pub use float::*;
+pub use function::*;
+pub use groebner::*;
+pub use latex::*;
 pub use matrix::*;
+pub use order::*;
+pub use parse::*;
 pub use poly::*;
 pub use quat::*;
+pub use rational::*;
+pub use solve::*;
 pub use vector::*;
+pub use zp::*;
+
+pub use num_bigint::BigInt;
+#[cfg(feature = "bigint")]
+pub use num_rational::BigRational;
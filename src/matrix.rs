@@ -1,8 +1,9 @@
-use crate::Vector;
+use crate::mpoly::MPoly;
+use crate::{Base, ExactCoeff, IntegerCoeff, Poly, RationalFunction, SignedCoeff, Vector, Zp, F64};
 use array_init::array_init;
-use num_traits::Zero;
+use num_traits::{One, Signed, Zero};
 use std::fmt;
-use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Matrix<T, const R: usize, const C: usize> {
@@ -20,6 +21,9 @@ impl<T: Clone, const R: usize, const C: usize> Matrix<T, R, C> {
         Vector { data }
     }
 
+    /// # Safety
+    ///
+    /// `row` must be less than `R` and `col` must be less than `C`.
     pub unsafe fn get_unchecked(&self, (row, col): (usize, usize)) -> T {
         self.data.get_unchecked(col).get_unchecked(row).clone()
     }
@@ -103,9 +107,9 @@ impl<T: MulAssign + Clone, const R: usize, const C: usize> Mul<T> for Matrix<T,
 
     fn mul(self, rhs: T) -> Self::Output {
         let mut data = self.data;
-        for c in 0..C {
-            for r in 0..R {
-                data[c][r] *= rhs.clone();
+        for col in data.iter_mut() {
+            for value in col.iter_mut() {
+                *value *= rhs.clone();
             }
         }
         Self { data }
@@ -117,9 +121,9 @@ impl<T: MulAssign + Clone, const R: usize, const C: usize> Mul<T> for &Matrix<T,
 
     fn mul(self, rhs: T) -> Self::Output {
         let mut data = self.data.clone();
-        for c in 0..C {
-            for r in 0..R {
-                data[c][r] *= rhs.clone();
+        for col in data.iter_mut() {
+            for value in col.iter_mut() {
+                *value *= rhs.clone();
             }
         }
         Matrix { data }
@@ -131,9 +135,9 @@ impl<T: Neg<Output = T> + Clone, const R: usize, const C: usize> Neg for Matrix<
 
     fn neg(self) -> Self::Output {
         let mut data = self.data;
-        for c in 0..C {
-            for r in 0..R {
-                data[c][r] = -data[c][r].clone();
+        for col in data.iter_mut() {
+            for value in col.iter_mut() {
+                *value = -value.clone();
             }
         }
         Matrix { data }
@@ -145,9 +149,9 @@ impl<T: Neg<Output = T> + Clone, const R: usize, const C: usize> Neg for &Matrix
 
     fn neg(self) -> Self::Output {
         let mut data = self.data.clone();
-        for c in 0..C {
-            for r in 0..R {
-                data[c][r] = -data[c][r].clone();
+        for col in data.iter_mut() {
+            for value in col.iter_mut() {
+                *value = -value.clone();
             }
         }
         Matrix { data }
@@ -206,110 +210,464 @@ impl<
     }
 }
 
-impl<T: Clone> Matrix<T, 1, 1> {
-    pub fn determinant(&self) -> T {
-        self.data[0][0].clone()
+/// Entry of a square matrix, choosing how its determinant is computed.
+///
+/// `Matrix::determinant` requires this trait at every size, so even 1×1 to 3×3 entries
+/// need `Zero`, `One` and `Clone` besides `Add`, `Sub` and `Mul`.
+/// Entries whose `exact_div` divides use fraction-free Bareiss elimination, the others
+/// and any elimination meeting a division that fails use cofactor expansion, which
+/// never divides.
+pub trait DetEntry:
+    Mul<Output = Self> + Add<Output = Self> + Sub<Output = Self> + Zero + One + Clone
+{
+    /// `self / rhs` when `rhs` divides `self`, `None` if entries cannot be divided.
+    fn exact_div(&self, _rhs: &Self) -> Option<Self> {
+        None
+    }
+
+    /// Size compared when pivoting, rounded entries override it with `abs`
+    /// so the largest pivot is taken.
+    fn magnitude(&self) -> f64 {
+        1.0
+    }
+
+    /// Determinant of the square matrix given by its rows, overridden by entries
+    /// eliminated in another representation.
+    fn determinant(rows: Vec<Vec<Self>>) -> Self {
+        determinant_of(rows)
     }
 }
 
-impl<T: Mul<Output = T> + Sub<Output = T> + Clone> Matrix<T, 2, 2> {
-    pub fn determinant(&self) -> T {
-        self.data[0][0].clone() * self.data[1][1].clone()
-            - self.data[0][1].clone() * self.data[1][0].clone()
+macro_rules! det_entry_impl(
+    ($($t:ty),*) => {
+        $(
+            impl DetEntry for $t {
+                fn exact_div(&self, rhs: &Self) -> Option<Self> {
+                    (!rhs.is_zero()).then(|| self.clone() / rhs.clone())
+                }
+            }
+        )*
+    }
+);
+
+det_entry_impl!(i8, i16, i32, i64, i128, isize, crate::BigInt);
+
+#[cfg(feature = "bigint")]
+det_entry_impl!(crate::BigRational);
+
+macro_rules! float_det_entry_impl(
+    ($($t:ty),*) => {
+        $(
+            impl DetEntry for $t {
+                fn exact_div(&self, rhs: &Self) -> Option<Self> {
+                    (!rhs.is_zero()).then(|| *self / *rhs)
+                }
+
+                fn magnitude(&self) -> f64 {
+                    f64::from(self.abs())
+                }
+            }
+        )*
+    }
+);
+
+float_det_entry_impl!(f32, f64, F64);
+
+impl<const P: u64> DetEntry for Zp<P> {
+    fn exact_div(&self, rhs: &Self) -> Option<Self> {
+        rhs.inv().map(|inv| *self * inv)
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Clone> Matrix<T, 3, 3> {
-    pub fn determinant(&self) -> T {
-        unsafe {
-            let m11 = self.get_unchecked((0, 0));
-            let m12 = self.get_unchecked((0, 1));
-            let m13 = self.get_unchecked((0, 2));
-            let m21 = self.get_unchecked((1, 0));
-            let m22 = self.get_unchecked((1, 1));
-            let m23 = self.get_unchecked((1, 2));
-            let m31 = self.get_unchecked((2, 0));
-            let m32 = self.get_unchecked((2, 1));
-            let m33 = self.get_unchecked((2, 2));
-
-            let minor_m12_m23 = m22.clone() * m33.clone() - m32.clone() * m23.clone();
-            let minor_m11_m23 = m21.clone() * m33 - m31.clone() * m23;
-            let minor_m11_m22 = m21 * m32 - m31 * m22;
-
-            m11 * minor_m12_m23 - m12 * minor_m11_m23 + m13 * minor_m11_m22
+impl<T: ExactCoeff> DetEntry for Poly<T> {
+    fn exact_div(&self, rhs: &Self) -> Option<Self> {
+        self.clone() / rhs.clone()
+    }
+
+    /// Fraction-free elimination as in `Matrix::echelon_fraction_free`,
+    /// choosing the sparsest pivots.
+    fn determinant(rows: Vec<Vec<Self>>) -> Self {
+        let n = rows.len();
+        if n == 0 {
+            return Poly::one();
+        }
+        let Some((reduced, vars, rank, swapped)) = bareiss_polys(&rows, true) else {
+            return determinant_of(rows);
+        };
+        if rank < n {
+            return Poly::zero();
+        }
+        let det = reduced[n - 1][n - 1].to_poly(&vars);
+        if swapped {
+            -det
+        } else {
+            det
         }
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero + Clone> Matrix<T, 4, 4> {
-    pub fn minor(&self, i: usize, j: usize) -> T {
-        let m = Matrix::<T, 3, 3> {
-            data: array_init(|c| {
-                array_init(|r| {
-                    self.data[if c < j { c } else { c + 1 }][if r < i { r } else { r + 1 }].clone()
-                })
-            }),
-        };
-        m.determinant()
+/// Reduced rows in expanded form, their variables, the rank and whether the sign changed.
+type ReducedPolys<T> = (Vec<Vec<MPoly<T>>>, Vec<Base<T>>, usize, bool);
+
+/// Bareiss elimination of polynomial rows in expanded form, `None` if a division is
+/// not exact, which only overflowing coefficients cause.
+pub(crate) fn bareiss_polys<T: ExactCoeff>(
+    rows: &[Vec<Poly<T>>],
+    full_pivoting: bool,
+) -> Option<ReducedPolys<T>> {
+    let cols = rows.first().map_or(0, Vec::len);
+    let entries = rows.iter().flatten().collect::<Vec<_>>();
+    let (entries, vars) = MPoly::from_polys(&entries);
+    let mut rows = entries
+        .chunks(cols.max(1))
+        .map(<[_]>::to_vec)
+        .collect::<Vec<_>>();
+    let (rank, swapped) = bareiss(&mut rows, full_pivoting)?;
+    Some((rows, vars, rank, swapped))
+}
+
+/// Without exact polynomial division over GF(P), determinants use cofactor expansion.
+impl<const P: u64> DetEntry for Poly<Zp<P>> {}
+
+/// Rounded coefficients leave remainders where Bareiss divides exactly, so determinants
+/// use cofactor expansion.
+impl DetEntry for Poly<F64> {}
+
+impl<T: IntegerCoeff> DetEntry for RationalFunction<T> {
+    fn exact_div(&self, rhs: &Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self.clone() / rhs.clone())
     }
-    pub fn determinant(&self) -> T {
-        let mut det = T::zero();
-        for (c, v) in self.row(0).data.into_iter().enumerate() {
-            if c % 2 == 0 {
-                det = det + v * self.minor(0, c);
-            } else {
-                det = det - v * self.minor(0, c);
+}
+
+/// Entry with a quotient for every nonzero divisor.
+pub trait Field: DetEntry + Div<Output = Self> {}
+
+impl Field for f32 {}
+impl Field for f64 {}
+impl Field for F64 {}
+impl<const P: u64> Field for Zp<P> {}
+impl<T: IntegerCoeff> Field for RationalFunction<T> {}
+
+#[cfg(feature = "bigint")]
+impl Field for crate::BigRational {}
+
+/// Entry arithmetic used by `bareiss`.
+pub(crate) trait Eliminate: Clone {
+    fn vanishes(&self) -> bool;
+    fn zero_like(&self) -> Self;
+    /// Cost of the entry as a pivot.
+    fn size(&self) -> usize;
+    /// Among pivots of equal cost the largest is taken.
+    fn magnitude(&self) -> f64;
+    /// `a d - b c`
+    fn cross(a: &Self, d: &Self, b: &Self, c: &Self) -> Self;
+    fn divide(&self, rhs: &Self) -> Option<Self>;
+}
+
+impl<T: DetEntry> Eliminate for T {
+    fn vanishes(&self) -> bool {
+        self.is_zero()
+    }
+
+    fn zero_like(&self) -> Self {
+        T::zero()
+    }
+
+    fn size(&self) -> usize {
+        1
+    }
+
+    fn magnitude(&self) -> f64 {
+        DetEntry::magnitude(self)
+    }
+
+    fn cross(a: &Self, d: &Self, b: &Self, c: &Self) -> Self {
+        a.clone() * d.clone() - b.clone() * c.clone()
+    }
+
+    fn divide(&self, rhs: &Self) -> Option<Self> {
+        self.exact_div(rhs)
+    }
+}
+
+impl<T: SignedCoeff> Eliminate for MPoly<T> {
+    fn vanishes(&self) -> bool {
+        self.is_zero()
+    }
+
+    fn zero_like(&self) -> Self {
+        MPoly::constant(self.nvars, T::zero())
+    }
+
+    fn size(&self) -> usize {
+        self.terms.len()
+    }
+
+    fn magnitude(&self) -> f64 {
+        1.0
+    }
+
+    fn cross(a: &Self, d: &Self, b: &Self, c: &Self) -> Self {
+        a.mul(d).sub(&b.mul(c))
+    }
+
+    fn divide(&self, rhs: &Self) -> Option<Self> {
+        self.div_exact(rhs)
+    }
+}
+
+/// Fraction-free Bareiss elimination to row echelon form, in place.
+///
+/// With `full_pivoting` the pivot is searched in the whole remaining submatrix and
+/// columns are swapped too, which keeps sparse matrices sparse but only suits determinants.
+/// Returns the rank and whether an odd number of rows and columns were swapped, or
+/// `None` as soon as an entry cannot be divided.
+pub(crate) fn bareiss<T: Eliminate>(
+    rows: &mut [Vec<T>],
+    full_pivoting: bool,
+) -> Option<(usize, bool)> {
+    let cols = rows.first().map_or(0, Vec::len);
+    let mut rank = 0;
+    let mut swapped = false;
+    let mut prev: Option<T> = None;
+    for k in 0..cols {
+        if rank == rows.len() {
+            break;
+        }
+        let last = if full_pivoting { cols } else { k + 1 };
+        let view = &*rows;
+        let Some((pivot, col)) = (k..last)
+            .flat_map(|j| (rank..view.len()).map(move |i| (i, j)))
+            .filter(|&(i, j)| !view[i][j].vanishes())
+            .min_by(|&(i, j), &(p, q)| {
+                let (a, b) = (&view[i][j], &view[p][q]);
+                a.size()
+                    .cmp(&b.size())
+                    .then(b.magnitude().total_cmp(&a.magnitude()))
+            })
+        else {
+            continue;
+        };
+        if col != k {
+            for row in rows.iter_mut() {
+                row.swap(col, k);
             }
+            swapped = !swapped;
         }
+        if pivot != rank {
+            rows.swap(pivot, rank);
+            swapped = !swapped;
+        }
+        for i in rank + 1..rows.len() {
+            for j in k + 1..cols {
+                let value = T::cross(&rows[rank][k], &rows[i][j], &rows[i][k], &rows[rank][j]);
+                rows[i][j] = match &prev {
+                    Some(prev) => value.divide(prev)?,
+                    None => value,
+                };
+            }
+            rows[i][k] = rows[i][k].zero_like();
+        }
+        prev = Some(rows[rank][k].clone());
+        rank += 1;
+    }
+    Some((rank, swapped))
+}
+
+/// Determinant of a square matrix after `bareiss`, the last pivot.
+fn bareiss_determinant<T>(rows: &[Vec<T>], rank: usize, swapped: bool) -> T
+where
+    T: Sub<Output = T> + Zero + One + Clone,
+{
+    let n = rows.len();
+    if rank < n {
+        return T::zero();
+    }
+    let det = rows.last().map_or_else(T::one, |row| row[n - 1].clone());
+    if swapped {
+        T::zero() - det
+    } else {
         det
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero + Clone> Matrix<T, 5, 5> {
-    pub fn minor(&self, i: usize, j: usize) -> T {
-        let m = Matrix::<T, 4, 4> {
-            data: array_init(|c| {
-                array_init(|r| {
-                    self.data[if c < j { c } else { c + 1 }][if r < i { r } else { r + 1 }].clone()
-                })
-            }),
-        };
-        m.determinant()
+/// Laplace expansion along the first row, skipping zero entries.
+fn cofactor<T: DetEntry>(rows: &[Vec<T>]) -> T {
+    match rows.len() {
+        0 => T::one(),
+        1 => rows[0][0].clone(),
+        2 => rows[0][0].clone() * rows[1][1].clone() - rows[0][1].clone() * rows[1][0].clone(),
+        _ => {
+            let mut det = T::zero();
+            for (c, v) in rows[0].iter().enumerate() {
+                if v.is_zero() {
+                    continue;
+                }
+                let term = v.clone() * cofactor(&submatrix(rows, 0, c));
+                det = if c % 2 == 0 { det + term } else { det - term };
+            }
+            det
+        }
     }
-    pub fn determinant(&self) -> T {
-        let mut det = T::zero();
-        for (c, v) in self.row(0).data.into_iter().enumerate() {
-            if c % 2 == 0 {
-                det = det + v * self.minor(0, c);
-            } else {
-                det = det - v * self.minor(0, c);
+}
+
+fn submatrix<T: Clone>(rows: &[Vec<T>], i: usize, j: usize) -> Vec<Vec<T>> {
+    rows.iter()
+        .enumerate()
+        .filter(|&(r, _)| r != i)
+        .map(|(_, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|&(c, _)| c != j)
+                .map(|(_, v)| v.clone())
+                .collect()
+        })
+        .collect()
+}
+
+pub(crate) fn determinant_of<T: DetEntry>(rows: Vec<Vec<T>>) -> T {
+    match rows.len() {
+        0..=2 => cofactor(&rows),
+        _ => {
+            let mut reduced = rows.clone();
+            match bareiss(&mut reduced, true) {
+                Some((rank, swapped)) => bareiss_determinant(&reduced, rank, swapped),
+                None => cofactor(&rows),
             }
         }
-        det
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero + Clone> Matrix<T, 6, 6> {
+impl<T: DetEntry, const N: usize> Matrix<T, N, N> {
+    pub(crate) fn rows(&self) -> Vec<Vec<T>> {
+        (0..N)
+            .map(|r| (0..N).map(|c| self.data[c][r].clone()).collect())
+            .collect()
+    }
+
+    /// Determinant of the matrix without row `i` and column `j`.
     pub fn minor(&self, i: usize, j: usize) -> T {
-        let m = Matrix::<T, 5, 5> {
-            data: array_init(|c| {
-                array_init(|r| {
-                    self.data[if c < j { c } else { c + 1 }][if r < i { r } else { r + 1 }].clone()
-                })
-            }),
-        };
-        m.determinant()
+        T::determinant(submatrix(&self.rows(), i, j))
     }
+
     pub fn determinant(&self) -> T {
-        let mut det = T::zero();
-        for (c, v) in self.row(0).data.into_iter().enumerate() {
-            if c % 2 == 0 {
-                det = det + v * self.minor(0, c);
+        T::determinant(self.rows())
+    }
+
+    /// Transposed matrix of cofactors, `self * adj = det * I`.
+    ///
+    /// Needs no division, so it suits polynomial entries; over a field `inverse` is cheaper.
+    pub fn adjugate(&self) -> Self {
+        let rows = self.rows();
+        let data = array_init(|c| {
+            array_init(|r| {
+                let minor = T::determinant(submatrix(&rows, c, r));
+                if (r + c) % 2 == 0 {
+                    minor
+                } else {
+                    T::zero() - minor
+                }
+            })
+        });
+        Matrix { data }
+    }
+}
+
+/// Entries within this distance of zero count as zero when pivoting, the size of the
+/// rounding error for floats and zero for exact fields.
+pub(crate) fn pivot_tolerance<'a, T: Field + 'a>(
+    entries: impl IntoIterator<Item = &'a T>,
+    size: usize,
+) -> f64 {
+    let scale = entries
+        .into_iter()
+        .map(DetEntry::magnitude)
+        .fold(0.0, f64::max);
+    scale * f64::EPSILON * size as f64
+}
+
+/// Row from `start` on with the largest entry in column `k`, `None` if they all vanish.
+pub(crate) fn partial_pivot<T: Field>(
+    rows: &[Vec<T>],
+    start: usize,
+    k: usize,
+    tolerance: f64,
+) -> Option<usize> {
+    (start..rows.len())
+        .filter(|&i| !rows[i][k].is_zero() && rows[i][k].magnitude() > tolerance)
+        .reduce(|best, i| {
+            if rows[i][k].magnitude() > rows[best][k].magnitude() {
+                i
             } else {
-                det = det - v * self.minor(0, c);
+                best
             }
+        })
+}
+
+impl<T: Field, const N: usize> Matrix<T, N, N> {
+    /// Gauss–Jordan elimination with partial pivoting, `None` for a singular matrix.
+    pub fn inverse(&self) -> Option<Self> {
+        let mut rows = self.rows();
+        let tolerance = pivot_tolerance(rows.iter().flatten(), N);
+        for (r, row) in rows.iter_mut().enumerate() {
+            row.extend((0..N).map(|c| if r == c { T::one() } else { T::zero() }));
         }
-        det
+        for k in 0..N {
+            let pivot = partial_pivot(&rows, k, k, tolerance)?;
+            rows.swap(pivot, k);
+            let scale = T::one() / rows[k][k].clone();
+            for value in &mut rows[k][k..] {
+                *value = value.clone() * scale.clone();
+            }
+            let pivot_row = rows[k].clone();
+            for (i, row) in rows.iter_mut().enumerate() {
+                if i == k || row[k].is_zero() {
+                    continue;
+                }
+                let factor = row[k].clone();
+                for (value, above) in row[k..].iter_mut().zip(&pivot_row[k..]) {
+                    *value = value.clone() - factor.clone() * above.clone();
+                }
+            }
+        }
+        Some(Matrix {
+            data: array_init(|c| array_init(|r| rows[r][N + c].clone())),
+        })
+    }
+}
+
+/// Row echelon form computed by fraction-free elimination.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Echelon<T, const R: usize, const C: usize> {
+    /// Each entry is a minor of the original matrix.
+    pub matrix: Matrix<T, R, C>,
+    pub rank: usize,
+    /// Determinant of a square matrix, the same as `Matrix::determinant`, `None` otherwise.
+    pub determinant: Option<T>,
+}
+
+impl<T: ExactCoeff, const R: usize, const C: usize> Matrix<Poly<T>, R, C> {
+    /// Bareiss elimination with exact polynomial division.
+    ///
+    /// Runs in polynomial time, unlike cofactor expansion.
+    /// `None` if a division is not exact, which only overflowing coefficients cause.
+    pub fn echelon_fraction_free(&self) -> Option<Echelon<Poly<T>, R, C>> {
+        let rows = (0..R)
+            .map(|r| (0..C).map(|c| self.data[c][r].clone()).collect())
+            .collect::<Vec<Vec<_>>>();
+        let (rows, vars, rank, swapped) = bareiss_polys(&rows, false)?;
+        let rows = rows
+            .iter()
+            .map(|row| row.iter().map(|entry| entry.to_poly(&vars)).collect())
+            .collect::<Vec<Vec<_>>>();
+        let determinant = (R == C).then(|| bareiss_determinant(&rows, rank, swapped));
+        Some(Echelon {
+            matrix: Matrix {
+                data: array_init(|c| array_init(|r| rows[r][c].clone())),
+            },
+            rank,
+            determinant,
+        })
     }
 }
 
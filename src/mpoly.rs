@@ -0,0 +1,439 @@
+use crate::{Base, Factor, IntegerCoeff, Mono, Poly, SignedCoeff, TermOrder};
+use num_bigint::BigInt;
+use num_traits::Signed;
+use std::collections::BTreeMap;
+
+/// Polynomial in distributed form over a list of variables.
+///
+/// Terms are sorted by descending lexicographic order of their exponents,
+/// the first variable being the most significant one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MPoly<T> {
+    pub nvars: usize,
+    pub terms: Vec<(Vec<i32>, T)>,
+}
+
+/// Greatest common divisor of two coefficients, always non-negative.
+pub(crate) fn coeff_gcd<T: SignedCoeff>(a: &T, b: &T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while !b.is_zero() {
+        let r = a % b.clone();
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Whether `divisor` divides `coeff`, always true for a nonzero divisor in a field.
+pub(crate) fn coeff_divides<T: SignedCoeff>(divisor: &T, coeff: &T) -> bool {
+    !divisor.is_zero()
+        && (!(T::one() / divisor.clone()).is_zero() || (coeff.clone() % divisor.clone()).is_zero())
+}
+
+impl<T: SignedCoeff> MPoly<T> {
+    pub fn constant(nvars: usize, coeff: T) -> Self {
+        let mut terms = vec![];
+        if !coeff.is_zero() {
+            terms.push((vec![0; nvars], coeff));
+        }
+        MPoly { nvars, terms }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn from_map(nvars: usize, map: BTreeMap<Vec<i32>, T>) -> Self {
+        let terms = map
+            .into_iter()
+            .rev()
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .collect();
+        MPoly { nvars, terms }
+    }
+
+    /// Convert a polynomial, nested polynomial factors are expanded,
+    /// other bases are treated as variables and appended to `vars` when new.
+    pub fn from_poly(poly: &Poly<T>, vars: &mut Vec<Base<T>>) -> Self {
+        let mut products = vec![];
+        for term in &poly.terms {
+            let mut product = MPoly::constant(vars.len(), term.coeff.clone());
+            for factor in &term.factors {
+                match &factor.base {
+                    Base::Poly(inner) if factor.power > 0 => {
+                        let inner = MPoly::from_poly(inner, vars);
+                        product.resize(vars.len());
+                        for _ in 0..factor.power {
+                            product = product.mul(&inner);
+                        }
+                    }
+                    base => {
+                        let index = match vars.iter().position(|var| var == base) {
+                            Some(index) => index,
+                            None => {
+                                vars.push(base.clone());
+                                vars.len() - 1
+                            }
+                        };
+                        product.resize(vars.len());
+                        for (exps, _) in &mut product.terms {
+                            exps[index] += factor.power;
+                        }
+                    }
+                }
+            }
+            products.push(product);
+        }
+        let nvars = vars.len();
+        let mut result = BTreeMap::new();
+        for mut product in products {
+            product.resize(nvars);
+            for (exps, coeff) in product.terms {
+                *result.entry(exps).or_insert_with(T::zero) += coeff;
+            }
+        }
+        MPoly::from_map(nvars, result)
+    }
+
+    /// Convert several polynomials over a common list of variables, sorted by base
+    /// so that the variable order does not depend on the order of appearance.
+    pub fn from_polys(polys: &[&Poly<T>]) -> (Vec<Self>, Vec<Base<T>>) {
+        Self::from_polys_in(polys, &[])
+    }
+
+    /// Convert several polynomials over a common list of variables starting with `first`,
+    /// followed by the other variables sorted by base.
+    pub fn from_polys_in(polys: &[&Poly<T>], first: &[Base<T>]) -> (Vec<Self>, Vec<Base<T>>) {
+        let mut vars = first.to_vec();
+        let mut result = polys
+            .iter()
+            .map(|poly| MPoly::from_poly(poly, &mut vars))
+            .collect::<Vec<_>>();
+        let mut perm = (first.len()..vars.len()).collect::<Vec<_>>();
+        perm.sort_by(|a, b| vars[*a].cmp(&vars[*b]));
+        perm.splice(0..0, 0..first.len());
+        for poly in &mut result {
+            poly.resize(vars.len());
+            let map = poly
+                .terms
+                .iter()
+                .map(|(exps, coeff)| (perm.iter().map(|i| exps[*i]).collect(), coeff.clone()))
+                .collect();
+            *poly = MPoly::from_map(vars.len(), map);
+        }
+        let vars = perm.iter().map(|i| vars[*i].clone()).collect();
+        (result, vars)
+    }
+
+    pub fn to_poly(&self, vars: &[Base<T>]) -> Poly<T> {
+        let mut terms = self
+            .terms
+            .iter()
+            .map(|(exps, coeff)| {
+                let mut factors = exps
+                    .iter()
+                    .zip(vars)
+                    .filter(|(power, _)| **power != 0)
+                    .map(|(power, base)| Factor {
+                        base: base.clone(),
+                        power: *power,
+                    })
+                    .collect::<Vec<_>>();
+                factors.sort_by(|a, b| a.base.cmp(&b.base));
+                Mono {
+                    coeff: coeff.clone(),
+                    factors,
+                }
+            })
+            .collect::<Vec<_>>();
+        terms.sort_by(|a, b| a.factors.cmp(&b.factors));
+        Poly { terms }
+    }
+
+    /// Extend the exponent vectors to `nvars` variables.
+    pub fn resize(&mut self, nvars: usize) {
+        if nvars > self.nvars {
+            for (exps, _) in &mut self.terms {
+                exps.resize(nvars, 0);
+            }
+            self.nvars = nvars;
+        }
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut map = self.terms.iter().cloned().collect::<BTreeMap<_, _>>();
+        for (exps, coeff) in &other.terms {
+            *map.entry(exps.clone()).or_insert_with(T::zero) += coeff.clone();
+        }
+        MPoly::from_map(self.nvars, map)
+    }
+
+    pub fn neg(&self) -> Self {
+        MPoly {
+            nvars: self.nvars,
+            terms: self
+                .terms
+                .iter()
+                .map(|(exps, coeff)| (exps.clone(), -coeff.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut map = BTreeMap::new();
+        for (exps, coeff) in &self.terms {
+            for (exps2, coeff2) in &other.terms {
+                let exps = exps.iter().zip(exps2).map(|(a, b)| a + b).collect();
+                *map.entry(exps).or_insert_with(T::zero) += coeff.clone() * coeff2.clone();
+            }
+        }
+        MPoly::from_map(self.nvars, map)
+    }
+
+    pub fn mul_coeff(&self, coeff: T) -> Self {
+        MPoly {
+            nvars: self.nvars,
+            terms: self
+                .terms
+                .iter()
+                .map(|(exps, c)| (exps.clone(), c.clone() * coeff.clone()))
+                .filter(|(_, c)| !c.is_zero())
+                .collect(),
+        }
+    }
+
+    /// Exact quotient of the division, `None` if `other` does not divide `self`.
+    pub fn div_exact(&self, other: &Self) -> Option<Self> {
+        let (lead_exps, lead_coeff) = other.terms.first()?;
+        let mut remainder = self.terms.iter().cloned().collect::<BTreeMap<_, _>>();
+        let mut quotient = vec![];
+        while let Some((exps, coeff)) = remainder.pop_last() {
+            let exps = exps
+                .iter()
+                .zip(lead_exps)
+                .map(|(a, b)| a - b)
+                .collect::<Vec<_>>();
+            if exps.iter().any(|e| *e < 0) || !coeff_divides(lead_coeff, &coeff) {
+                return None;
+            }
+            let coeff = coeff / lead_coeff.clone();
+            for (exps2, coeff2) in &other.terms[1..] {
+                let key = exps.iter().zip(exps2).map(|(a, b)| a + b).collect();
+                let value = remainder.entry(key).or_insert_with(T::zero);
+                *value = value.clone() - coeff.clone() * coeff2.clone();
+                if value.is_zero() {
+                    let key = exps
+                        .iter()
+                        .zip(exps2)
+                        .map(|(a, b)| a + b)
+                        .collect::<Vec<_>>();
+                    remainder.remove(&key);
+                }
+            }
+            quotient.push((exps, coeff));
+        }
+        Some(MPoly {
+            nvars: self.nvars,
+            terms: quotient,
+        })
+    }
+
+    pub fn div_coeff(&self, coeff: T) -> Self {
+        MPoly {
+            nvars: self.nvars,
+            terms: self
+                .terms
+                .iter()
+                .map(|(exps, c)| (exps.clone(), c.clone() / coeff.clone()))
+                .collect(),
+        }
+    }
+
+    /// Greatest common divisor of the coefficients, with the sign of the leading term.
+    pub fn content(&self) -> T {
+        let gcd = self
+            .terms
+            .iter()
+            .fold(T::zero(), |acc, (_, coeff)| coeff_gcd(&acc, coeff));
+        match self.terms.first() {
+            Some((_, lc)) if Signed::is_negative(lc) => -gcd,
+            _ => gcd,
+        }
+    }
+
+    /// Lowest power of each variable over all terms.
+    pub fn min_exponents(&self) -> Vec<i32> {
+        let mut mins = match self.terms.first() {
+            Some((exps, _)) => exps.clone(),
+            None => return vec![0; self.nvars],
+        };
+        for (exps, _) in &self.terms[1..] {
+            for (min, exp) in mins.iter_mut().zip(exps) {
+                *min = (*min).min(*exp);
+            }
+        }
+        mins
+    }
+
+    /// Divide every term by the monomial with the given exponents.
+    pub fn shift(&self, exps: &[i32]) -> Self {
+        MPoly {
+            nvars: self.nvars,
+            terms: self
+                .terms
+                .iter()
+                .map(|(e, coeff)| {
+                    (
+                        e.iter().zip(exps).map(|(a, b)| a - b).collect(),
+                        coeff.clone(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Indices of the variables occurring in the polynomial.
+    pub fn variables(&self) -> Vec<usize> {
+        (0..self.nvars)
+            .filter(|&i| self.terms.iter().any(|(exps, _)| exps[i] != 0))
+            .collect()
+    }
+
+    pub fn degree(&self, var: usize) -> i32 {
+        self.terms
+            .iter()
+            .map(|(exps, _)| exps[var])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Coefficients with respect to variable `var`, highest power first.
+    pub fn coeffs_in(&self, var: usize) -> Vec<(i32, MPoly<T>)> {
+        let mut map = BTreeMap::new();
+        for (exps, coeff) in &self.terms {
+            let mut exps = exps.clone();
+            let power = std::mem::replace(&mut exps[var], 0);
+            map.entry(power)
+                .or_insert_with(BTreeMap::new)
+                .insert(exps, coeff.clone());
+        }
+        map.into_iter()
+            .rev()
+            .map(|(power, terms)| (power, MPoly::from_map(self.nvars, terms)))
+            .collect()
+    }
+
+    /// Leading term under the given monomial order.
+    pub fn leading(&self, order: &TermOrder<T>) -> Option<&(Vec<i32>, T)> {
+        self.terms.iter().max_by(|a, b| order.compare(&a.0, &b.0))
+    }
+
+    /// Leading coefficient with respect to variable `var`.
+    pub fn lc_in(&self, var: usize) -> MPoly<T> {
+        self.coeffs_in(var).remove(0).1
+    }
+
+    /// Multiply by `var^power`.
+    pub fn mul_var(&self, var: usize, power: i32) -> Self {
+        let mut result = self.clone();
+        for (exps, _) in &mut result.terms {
+            exps[var] += power;
+        }
+        result
+    }
+
+    pub fn derivative(&self, var: usize) -> Self {
+        let mut map = BTreeMap::new();
+        for (exps, coeff) in &self.terms {
+            if exps[var] != 0 {
+                let mut coeff = coeff.clone();
+                let mut n = T::zero();
+                for _ in 0..exps[var] {
+                    n += T::one();
+                }
+                coeff = coeff * n;
+                let mut exps = exps.clone();
+                exps[var] -= 1;
+                map.insert(exps, coeff);
+            }
+        }
+        MPoly::from_map(self.nvars, map)
+    }
+
+    /// Normalize to positive leading coefficient.
+    pub fn normalize(self) -> Self {
+        match self.terms.first() {
+            Some((_, lc)) if Signed::is_negative(lc) => self.neg(),
+            _ => self,
+        }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.terms
+            .iter()
+            .all(|(exps, _)| exps.iter().all(|e| *e == 0))
+    }
+
+    /// Dense coefficients with respect to variable `var`, lowest power first.
+    /// The polynomial must not contain other variables.
+    pub fn to_dense(&self, var: usize) -> Vec<T> {
+        let degree = self
+            .terms
+            .iter()
+            .map(|(exps, _)| exps[var])
+            .max()
+            .unwrap_or(0);
+        let mut dense = vec![T::zero(); degree as usize + 1];
+        for (exps, coeff) in &self.terms {
+            dense[exps[var] as usize] = coeff.clone();
+        }
+        dense
+    }
+
+    pub fn from_dense(nvars: usize, var: usize, dense: &[T]) -> Self {
+        let terms = dense
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .map(|(power, coeff)| {
+                let mut exps = vec![0; nvars];
+                exps[var] = power as i32;
+                (exps, coeff.clone())
+            })
+            .collect();
+        MPoly { nvars, terms }
+    }
+}
+
+impl<T: IntegerCoeff> MPoly<T> {
+    /// Same polynomial over arbitrary-precision integers, where intermediate results
+    /// of the gcd and the factorization cannot overflow.
+    pub fn to_bigint(&self) -> MPoly<BigInt> {
+        MPoly {
+            nvars: self.nvars,
+            terms: self
+                .terms
+                .iter()
+                .map(|(exps, coeff)| (exps.clone(), coeff.to_bigint()))
+                .collect(),
+        }
+    }
+
+    /// `None` if a coefficient is out of range of `T`.
+    pub fn from_bigint(f: &MPoly<BigInt>) -> Option<Self> {
+        let terms = f
+            .terms
+            .iter()
+            .map(|(exps, coeff)| Some((exps.clone(), T::from_bigint(coeff)?)))
+            .collect::<Option<_>>()?;
+        Some(MPoly {
+            nvars: f.nvars,
+            terms,
+        })
+    }
+}
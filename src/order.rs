@@ -0,0 +1,122 @@
+use crate::{Base, Coeff, Mono, Poly};
+use std::cmp::Ordering;
+
+/// Monomial order, comparing exponent vectors whose first variable is the most significant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MonomialOrder {
+    /// lexicographic order
+    #[default]
+    Lex,
+    /// graded lexicographic order, total degree first
+    GrLex,
+    /// graded reverse lexicographic order, total degree first
+    GrevLex,
+}
+
+impl MonomialOrder {
+    pub fn compare(&self, a: &[i32], b: &[i32]) -> Ordering {
+        let degree = |exps: &[i32]| exps.iter().sum::<i32>();
+        match self {
+            Self::Lex => a.cmp(b),
+            Self::GrLex => degree(a).cmp(&degree(b)).then_with(|| a.cmp(b)),
+            Self::GrevLex => degree(a)
+                .cmp(&degree(b))
+                .then_with(|| b.iter().rev().cmp(a.iter().rev())),
+        }
+    }
+}
+
+/// Monomial order over a given variable order, made of blocks of variables.
+///
+/// Monomials are compared block by block, each block with its own order.
+/// Variables not listed are placed after the listed ones, sorted by base,
+/// and belong to the last block.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TermOrder<T: Coeff> {
+    pub vars: Vec<Base<T>>,
+    pub blocks: Vec<(usize, MonomialOrder)>,
+}
+
+impl<T: Coeff> TermOrder<T> {
+    /// Single block order with `vars` as the most significant variables, in that order.
+    pub fn new(order: MonomialOrder, vars: Vec<Base<T>>) -> Self {
+        let blocks = vec![(vars.len(), order)];
+        TermOrder { vars, blocks }
+    }
+
+    /// Block order, every variable of a block being greater than those of the following blocks.
+    pub fn block(blocks: Vec<(Vec<Base<T>>, MonomialOrder)>) -> Self {
+        let mut order = TermOrder {
+            vars: vec![],
+            blocks: vec![],
+        };
+        for (vars, monomial_order) in blocks {
+            order.blocks.push((vars.len(), monomial_order));
+            order.vars.extend(vars);
+        }
+        order
+    }
+
+    /// Elimination order for `vars`, which are greater than any other variable.
+    pub fn elimination(vars: Vec<Base<T>>, order: MonomialOrder) -> Self {
+        Self::block(vec![(vars, order), (vec![], order)])
+    }
+
+    /// Compare exponent vectors of the listed variables followed by the others.
+    pub fn compare(&self, a: &[i32], b: &[i32]) -> Ordering {
+        let mut start = 0;
+        for (index, (size, order)) in self.blocks.iter().enumerate() {
+            let end = if index + 1 == self.blocks.len() {
+                a.len()
+            } else {
+                start + size
+            };
+            let ordering = order.compare(&a[start..end], &b[start..end]);
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            start = end;
+        }
+        a[start..].cmp(&b[start..])
+    }
+
+    /// Compare monomials by their factors, ignoring the coefficients.
+    pub fn compare_terms(&self, a: &Mono<T>, b: &Mono<T>) -> Ordering {
+        let mut bases = self.vars.iter().collect::<Vec<_>>();
+        let mut others = a
+            .factors
+            .iter()
+            .chain(&b.factors)
+            .map(|factor| &factor.base)
+            .filter(|base| !self.vars.contains(base))
+            .collect::<Vec<_>>();
+        others.sort();
+        others.dedup();
+        bases.extend(others);
+        self.compare(&a.powers_of(&bases), &b.powers_of(&bases))
+    }
+}
+
+impl<T: Coeff> From<MonomialOrder> for TermOrder<T> {
+    fn from(order: MonomialOrder) -> Self {
+        TermOrder::new(order, vec![])
+    }
+}
+
+impl<T: Coeff> Poly<T> {
+    /// Sort the terms by the given order, leading term first, which is also the display order.
+    pub fn sort_terms(&mut self, order: &TermOrder<T>) {
+        self.terms.sort_by(|a, b| order.compare_terms(b, a));
+    }
+
+    /// Merge like terms and sort them by the given order, leading term first.
+    pub fn merge_terms_by(&mut self, order: &TermOrder<T>) {
+        self.merge_terms();
+        self.sort_terms(order);
+    }
+
+    /// Leading term under the given order.
+    pub fn leading_term(&self, order: &TermOrder<T>) -> Option<&Mono<T>> {
+        self.terms.iter().max_by(|a, b| order.compare_terms(a, b))
+    }
+}
@@ -0,0 +1,237 @@
+use crate::function::function_names;
+use crate::{lookup_function, Base, Coeff, Der, Factor, Mono, Poly, Sym};
+use num_traits::Num;
+use std::fmt;
+use std::str::FromStr;
+
+/// Error of parsing a polynomial, with the byte offset where it occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl<T: Coeff + Num> Poly<T> {
+    /// Parse the syntax emitted by `Display`, such as `- 2 a_x^2 b_y + sin^2θ + (u + v) w`.
+    ///
+    /// Juxtaposition is multiplication, `^` takes an integer power, parentheses give `Base::Poly`
+    /// factors and registered function names followed by a symbol or parentheses give `Base::Der`
+    /// factors. A function name joined to its symbol must end the ASCII word, so `sinθ` is
+    /// `sin θ` while `cost` is a symbol.
+    /// The result is normalized like `merge_terms` after `Mono::merge_factors` on every term,
+    /// nested polynomials included.
+    pub fn parse(input: &str) -> Result<Poly<T>, ParseError> {
+        let mut parser = Parser { input, position: 0 };
+        let poly = parser.poly()?;
+        parser.skip_whitespace();
+        if parser.position < input.len() {
+            return Err(parser.error("unexpected character"));
+        }
+        Ok(poly)
+    }
+}
+
+impl<T: Coeff + Num> FromStr for Poly<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Poly::parse(s)
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> ParseError {
+        ParseError {
+            position: self.position,
+            message: message.to_string(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.position += c.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &str {
+        let start = self.position;
+        while let Some(c) = self.peek().filter(|c| pred(*c)) {
+            self.position += c.len_utf8();
+        }
+        &self.input[start..self.position]
+    }
+
+    fn poly<T: Coeff + Num>(&mut self) -> Result<Poly<T>, ParseError> {
+        let mut terms: Vec<Mono<T>> = vec![];
+        self.skip_whitespace();
+        let mut negative = self.eat('-');
+        if !negative {
+            self.eat('+');
+        }
+        loop {
+            let mut term: Mono<T> = self.term()?;
+            if negative {
+                term.coeff = -term.coeff;
+            }
+            term.merge_factors();
+            terms.push(term);
+            self.skip_whitespace();
+            if self.eat('+') {
+                negative = false;
+            } else if self.eat('-') {
+                negative = true;
+            } else {
+                break;
+            }
+        }
+        let mut poly = Poly { terms };
+        poly.merge_terms();
+        Ok(poly)
+    }
+
+    fn term<T: Coeff + Num>(&mut self) -> Result<Mono<T>, ParseError> {
+        let mut coeff = T::one();
+        let mut factors: Vec<Factor<T>> = vec![];
+        let start = self.position;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(c) if c.is_ascii_digit() => {
+                    let position = self.position;
+                    let digits = self.take_while(|c| c.is_ascii_digit() || c == '.' || c == '/');
+                    // rational types only accept the `n/d` form
+                    let value = T::from_str_radix(digits, 10)
+                        .or_else(|_| T::from_str_radix(&format!("{}/1", digits), 10))
+                        .map_err(|_| ParseError {
+                            position,
+                            message: "invalid coefficient".to_string(),
+                        })?;
+                    coeff = coeff * value;
+                }
+                Some(c) if is_ident_start(c) || c == '(' => factors.push(self.factor()?),
+                _ => break,
+            }
+        }
+        if self.position == start {
+            return Err(self.error("expected a term"));
+        }
+        Ok(Mono { coeff, factors })
+    }
+
+    fn power(&mut self) -> Result<i32, ParseError> {
+        let position = self.position;
+        let sign = if self.eat('-') { -1 } else { 1 };
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        digits
+            .parse::<i32>()
+            .map(|power| sign * power)
+            .map_err(|_| ParseError {
+                position,
+                message: "expected an integer power".to_string(),
+            })
+    }
+
+    fn parenthesized<T: Coeff + Num>(&mut self) -> Result<Poly<T>, ParseError> {
+        let poly = self.poly()?;
+        self.skip_whitespace();
+        if !self.eat(')') {
+            return Err(self.error("expected `)`"));
+        }
+        Ok(poly)
+    }
+
+    fn factor<T: Coeff + Num>(&mut self) -> Result<Factor<T>, ParseError> {
+        let base = if self.eat('(') {
+            Base::Poly(self.parenthesized()?)
+        } else {
+            let ident = self.take_while(is_ident_char).to_string();
+            if lookup_function(&ident).is_some() {
+                return self.application(&ident);
+            }
+            // function of a symbol, e.g. `sinθ`, the longest name taken
+            let func = function_names()
+                .into_iter()
+                .filter(|func| {
+                    ident.strip_prefix(func.as_str()).is_some_and(|rest| {
+                        rest.chars()
+                            .next()
+                            .is_some_and(|c| !c.is_ascii() && is_ident_start(c))
+                    })
+                })
+                .max_by_key(String::len);
+            match func {
+                Some(func) => Base::Der(Der {
+                    param: Poly::from(Sym::from(ident[func.len()..].to_string())),
+                    func: func.into(),
+                }),
+                None => Base::Sym(ident.into()),
+            }
+        };
+        self.skip_whitespace();
+        let power = if self.eat('^') { self.power()? } else { 1 };
+        Ok(Factor { base, power })
+    }
+
+    /// Function application after its name, `sin^2θ`, `sin(a + b)`, `sin^2(a + b)` or `sin(a + b)^2`.
+    fn application<T: Coeff + Num>(&mut self, func: &str) -> Result<Factor<T>, ParseError> {
+        let power = if self.eat('^') { self.power()? } else { 1 };
+        self.skip_whitespace();
+        let param = if self.eat('(') {
+            self.parenthesized()?
+        } else {
+            match self.peek() {
+                Some(c) if is_ident_start(c) => {
+                    Poly::from(Sym::from(self.take_while(is_ident_char).to_string()))
+                }
+                _ => return Err(self.error("expected a function parameter")),
+            }
+        };
+        self.skip_whitespace();
+        let power = if self.eat('^') {
+            power * self.power()?
+        } else {
+            power
+        };
+        Ok(Factor {
+            base: Base::Der(Der {
+                func: func.to_string().into(),
+                param,
+            }),
+            power,
+        })
+    }
+}
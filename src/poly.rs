@@ -1,6 +1,8 @@
+use num_bigint::BigInt;
 use num_traits::{One, Signed, Zero};
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::convert::Infallible;
 use std::hash::Hash;
 use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use std::{fmt, vec};
@@ -14,31 +16,98 @@ pub trait Coeff:
     + Neg<Output = Self>
     + Hash
     + Eq
-    + PartialOrd
-    + Copy
+    + Clone
     + One
     + Zero
-    + Signed
     + fmt::Display
 {
+    /// Whether the coefficient is written with a minus sign, never the case for
+    /// coefficients without an order such as modular integers.
+    fn is_negative(&self) -> bool {
+        false
+    }
 }
 
-impl<T> Coeff for T where
-    T: Sized
-        + Add<Output = Self>
-        + AddAssign
-        + Sub<Output = Self>
-        + Mul<Output = Self>
-        + Neg<Output = Self>
-        + Hash
-        + Eq
-        + PartialOrd
-        + Copy
-        + One
-        + Zero
-        + Signed
-        + fmt::Display
-{
+macro_rules! signed_coeff_impl(
+    ($($t:ty),*) => {
+        $(
+            impl Coeff for $t {
+                fn is_negative(&self) -> bool {
+                    Signed::is_negative(self)
+                }
+            }
+
+            impl ExactCoeff for $t {}
+        )*
+    }
+);
+
+signed_coeff_impl!(i8, i16, i32, i64, i128, isize, BigInt);
+
+#[cfg(feature = "bigint")]
+signed_coeff_impl!(num_rational::BigRational);
+
+/// Signed when the leading term, written first, is.
+impl<T: Coeff> Coeff for Poly<T> {
+    fn is_negative(&self) -> bool {
+        self.terms
+            .first()
+            .is_some_and(|term| term.coeff.is_negative())
+    }
+}
+
+/// Coefficient with a sign and a Euclidean remainder, such as the integers.
+pub trait SignedCoeff: Coeff + Signed + PartialOrd {}
+
+impl<T: Coeff + Signed + PartialOrd> SignedCoeff for T {}
+
+/// Coefficient computed without rounding, so exact polynomial division can tell when it
+/// fails, required by the fraction-free algorithms.
+pub trait ExactCoeff: SignedCoeff {}
+
+/// Integer coefficient, required by the gcd, the factorization and rational functions,
+/// which compute with arbitrary-precision images of the coefficients.
+pub trait IntegerCoeff: ExactCoeff {
+    fn to_bigint(&self) -> BigInt;
+    /// `None` if the integer is out of range.
+    fn from_bigint(n: &BigInt) -> Option<Self>;
+}
+
+macro_rules! integer_coeff_impl(
+    ($($t:ty),*) => {
+        $(
+            impl IntegerCoeff for $t {
+                fn to_bigint(&self) -> BigInt {
+                    BigInt::from(*self)
+                }
+
+                fn from_bigint(n: &BigInt) -> Option<Self> {
+                    n.try_into().ok()
+                }
+            }
+        )*
+    }
+);
+
+integer_coeff_impl!(i8, i16, i32, i64, i128, isize);
+
+impl IntegerCoeff for BigInt {
+    fn to_bigint(&self) -> BigInt {
+        self.clone()
+    }
+
+    fn from_bigint(n: &BigInt) -> Option<Self> {
+        Some(n.clone())
+    }
+}
+
+/// Coefficient without its minus sign.
+pub(crate) fn abs<T: Coeff>(coeff: &T) -> T {
+    if coeff.is_negative() {
+        -coeff.clone()
+    } else {
+        coeff.clone()
+    }
 }
 
 /// Symbol represents a variable of a polynomial
@@ -144,16 +213,10 @@ impl<T: Coeff> Ord for Poly<T> {
 
 impl<T: Coeff> Base<T> {
     pub fn is_symbol(&self) -> bool {
-        match self {
-            Self::Sym(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Sym(_))
     }
     pub fn is_polynomial(&self) -> bool {
-        match self {
-            Self::Poly(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Poly(_))
     }
 }
 
@@ -196,7 +259,7 @@ impl<T: Coeff> Mono<T> {
                         factors.remove(index);
                     }
                     return Some(Mono {
-                        coeff: self.coeff,
+                        coeff: self.coeff.clone(),
                         factors,
                     });
                 } else {
@@ -250,13 +313,36 @@ impl<T: Coeff> Mono<T> {
             .any(|factor| factor.base.is_polynomial())
     }
 
+    /// Raise to the power `n`, multiplying the coefficient and the powers of the factors.
+    pub fn pow(&self, n: u32) -> Mono<T> {
+        let mut coeff = T::one();
+        for _ in 0..n {
+            coeff = coeff * self.coeff.clone();
+        }
+        let factors = match n {
+            0 => vec![],
+            _ => self
+                .factors
+                .iter()
+                .map(|factor| Factor {
+                    base: factor.base.clone(),
+                    power: factor.power * n as i32,
+                })
+                .collect(),
+        };
+        Mono { coeff, factors }
+    }
+
+    /// Multiply out `Base::Poly` factors with positive powers.
     pub fn expand(self) -> Poly<T> {
         let mut polynomials = vec![];
         let mut factors = vec![];
         for factor in self.factors {
             match factor.base {
-                // Todo: expand polynomial with power greater than 1
-                Base::Poly(poly) => polynomials.push(poly),
+                Base::Poly(poly) if factor.power > 0 => {
+                    polynomials.push(poly.pow(factor.power as u32))
+                }
+                // negative powers are kept as factors
                 _ => factors.push(factor),
             }
         }
@@ -268,10 +354,10 @@ impl<T: Coeff> Mono<T> {
         };
         if let Some(mut poly) = polynomials.pop() {
             while let Some(poly2) = polynomials.pop() {
-                poly = poly * poly2;
+                poly *= poly2;
             }
             poly.expand();
-            poly = poly * mono;
+            poly *= mono;
             poly
         } else {
             mono
@@ -378,50 +464,48 @@ impl<T: Coeff> From<Mono<T>> for Poly<T> {
 }
 
 impl<T: Coeff> Poly<T> {
-    pub fn apply<S: Into<Cow<'static, str>>>(&self, func: S) -> Poly<T> {
-        Self {
-            terms: vec![Mono {
-                coeff: T::one(),
-                factors: vec![Factor {
-                    base: Base::Der(Der {
-                        func: func.into(),
-                        param: self.clone(),
-                    }),
-                    power: 1,
-                }],
-            }],
-        }
-    }
-
     pub fn is_symbol(&self) -> bool {
         self.terms.len() == 1 && self.terms[0].is_symbol()
     }
 
     pub fn merge_terms(&mut self) {
+        let Ok(()) = self.merge_terms_with(|coeff, other| {
+            *coeff += other;
+            Ok::<_, Infallible>(())
+        });
+    }
+
+    /// Merge like terms, adding coefficients by `add`, and sort the terms.
+    pub(crate) fn merge_terms_with<E>(
+        &mut self,
+        mut add: impl FnMut(&mut T, T) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let terms = &mut self.terms;
         let mut i = 0;
-        while i < self.terms.len() {
-            let mut coeff = self.terms[i].coeff;
+        while i < terms.len() {
+            let mut coeff = terms[i].coeff.clone();
             let mut j = i + 1;
-            while j < self.terms.len() {
-                if self.terms[j].like(&self.terms[i]) {
-                    let term = self.terms.swap_remove(j);
-                    coeff += term.coeff;
+            while j < terms.len() {
+                if terms[j].like(&terms[i]) {
+                    let term = terms.swap_remove(j);
+                    add(&mut coeff, term.coeff)?;
                 } else {
                     j += 1;
                 }
             }
             if coeff.is_zero() {
-                self.terms.swap_remove(i);
+                terms.swap_remove(i);
             } else {
-                self.terms[i].coeff = coeff;
+                terms[i].coeff = coeff;
                 i += 1;
             }
         }
-        self.terms.sort_by(|a, b| a.factors.cmp(&b.factors));
+        terms.sort_by(|a, b| a.factors.cmp(&b.factors));
+        Ok(())
     }
 
     pub fn group_by(&mut self, bases: Vec<Base<T>>) {
-        let terms = std::mem::replace(&mut self.terms, vec![]);
+        let terms = std::mem::take(&mut self.terms);
         let items = terms.into_iter().map(|mono| mono.group_by(&bases)).fold(
             BTreeMap::new(),
             |mut acc, (term, factors)| {
@@ -538,8 +622,8 @@ impl<T: Coeff> Poly<T> {
             }
         }
         let mut final_terms = vec![];
-        for (factor, poly) in factors.into_iter().zip(collected_terms.into_iter()) {
-            if poly.terms.len() > 0 {
+        for (factor, poly) in factors.into_iter().zip(collected_terms) {
+            if !poly.terms.is_empty() {
                 final_terms.push(Mono {
                     coeff: T::one(),
                     factors: vec![
@@ -557,6 +641,23 @@ impl<T: Coeff> Poly<T> {
         Poly { terms: final_terms }
     }
 
+    /// Raise to the power `n` by repeated squaring, `p^0` being `1`.
+    pub fn pow(&self, n: u32) -> Poly<T> {
+        let mut result = Poly::one();
+        let mut base = self.clone();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result *= base.clone();
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.clone() * base;
+            }
+        }
+        result
+    }
+
     pub fn expand(&mut self) {
         let capacity = self.terms.len();
         let terms = std::mem::replace(&mut self.terms, Vec::with_capacity(capacity));
@@ -599,8 +700,6 @@ impl<T: Coeff> Poly<T> {
         }
         common_factors
     }
-
-    pub fn factorize(&self) {}
 }
 
 impl<T: Coeff> Neg for Poly<T> {
@@ -629,6 +728,7 @@ impl<T: Coeff> Add for Poly<T> {
 impl<T: Coeff> Sub for Poly<T> {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn sub(self, rhs: Self) -> Self::Output {
         self + rhs.neg()
     }
@@ -664,6 +764,15 @@ impl<T: Coeff> Zero for Poly<T> {
     }
 }
 
+impl<T: Coeff> One for Poly<T> {
+    fn one() -> Self {
+        Poly::from(Mono {
+            coeff: T::one(),
+            factors: vec![],
+        })
+    }
+}
+
 impl<T: Coeff> AddAssign for Poly<T> {
     fn add_assign(&mut self, rhs: Self) {
         *self = self.clone() + rhs;
@@ -688,18 +797,25 @@ impl fmt::Display for Sym {
     }
 }
 
+/// Function parameter, a symbol is joined to the function name unless it would continue
+/// the ASCII word of the name, e.g. `sinθ` but `sin x`.
+fn write_param<T: Coeff>(f: &mut fmt::Formatter<'_>, param: &Poly<T>) -> fmt::Result {
+    if !param.is_symbol() {
+        write!(f, "({})", param)
+    } else if param.to_string().starts_with(|c: char| c.is_ascii()) {
+        write!(f, " {}", param)
+    } else {
+        write!(f, "{}", param)
+    }
+}
+
 impl<T: Coeff> fmt::Display for Base<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Sym(sym) => write!(f, "{}", sym),
             Self::Der(der) => {
                 write!(f, "{}", der.func)?;
-                if der.param.is_symbol() {
-                    write!(f, "{}", der.param)?;
-                } else {
-                    write!(f, "({})", der.param)?;
-                }
-                Ok(())
+                write_param(f, &der.param)
             }
             Self::Poly(poly) => {
                 if poly.is_symbol() {
@@ -721,11 +837,7 @@ impl<T: Coeff> fmt::Display for Factor<T> {
             match &self.base {
                 Base::Der(der) => {
                     write!(f, "{}^{}", der.func, self.power)?;
-                    if der.param.is_symbol() {
-                        write!(f, "{}", der.param)?;
-                    } else {
-                        write!(f, "({})", der.param)?;
-                    }
+                    write_param(f, &der.param)?;
                 }
                 _ => {
                     write!(f, "{}^{}", self.base, self.power)?;
@@ -749,13 +861,13 @@ impl<T: Coeff> fmt::Display for Poly<T> {
                         write!(f, "- ")?;
                     }
                 } else {
-                    if term.coeff.is_positive() {
+                    if !term.coeff.is_negative() && !term.coeff.is_zero() {
                         write!(f, " + ")?;
                     } else {
                         write!(f, " - ")?;
                     }
                 }
-                let coeff = term.coeff.abs();
+                let coeff = abs(&term.coeff);
                 if !coeff.is_one() || term.factors.is_empty() {
                     write!(f, "{} ", coeff)?;
                 }
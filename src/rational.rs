@@ -0,0 +1,154 @@
+use crate::{IntegerCoeff, MonomialOrder, Poly, SignedCoeff, TermOrder};
+use num_traits::{One, Signed, Zero};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Quotient of polynomials in lowest terms.
+///
+/// The common factors given by `Poly::gcd` are cancelled and the denominator
+/// has a positive leading coefficient in lexicographic order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RationalFunction<T: SignedCoeff> {
+    numer: Poly<T>,
+    denom: Poly<T>,
+}
+
+impl<T: IntegerCoeff> RationalFunction<T> {
+    /// Panics if `denom` is zero.
+    pub fn new(numer: Poly<T>, denom: Poly<T>) -> Self {
+        assert!(!denom.is_zero(), "denominator is zero");
+        let mut result = RationalFunction { numer, denom };
+        result.normalize();
+        result
+    }
+
+    pub fn numer(&self) -> &Poly<T> {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &Poly<T> {
+        &self.denom
+    }
+
+    pub fn into_parts(self) -> (Poly<T>, Poly<T>) {
+        (self.numer, self.denom)
+    }
+
+    /// Numerator when the denominator is `1`.
+    pub fn to_poly(&self) -> Option<Poly<T>> {
+        self.denom.is_one().then(|| self.numer.clone())
+    }
+
+    /// Reciprocal, `None` for zero.
+    pub fn recip(&self) -> Option<Self> {
+        (!self.numer.is_zero()).then(|| Self::new(self.denom.clone(), self.numer.clone()))
+    }
+
+    fn normalize(&mut self) {
+        if self.numer.is_zero() {
+            self.denom = Poly::one();
+            return;
+        }
+        let gcd = self.numer.gcd(&self.denom);
+        self.numer = (self.numer.clone() / gcd.clone()).unwrap();
+        self.denom = (self.denom.clone() / gcd).unwrap();
+        let order = TermOrder::from(MonomialOrder::Lex);
+        if self
+            .denom
+            .leading_term(&order)
+            .is_some_and(|term| Signed::is_negative(&term.coeff))
+        {
+            self.numer = -self.numer.clone();
+            self.denom = -self.denom.clone();
+        }
+    }
+}
+
+impl<T: IntegerCoeff> From<Poly<T>> for RationalFunction<T> {
+    fn from(value: Poly<T>) -> Self {
+        Self::new(value, Poly::one())
+    }
+}
+
+impl<T: IntegerCoeff> Neg for RationalFunction<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        RationalFunction {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl<T: IntegerCoeff> Add for RationalFunction<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.denom == rhs.denom {
+            return Self::new(self.numer + rhs.numer, self.denom);
+        }
+        Self::new(
+            self.numer * rhs.denom.clone() + rhs.numer * self.denom.clone(),
+            self.denom * rhs.denom,
+        )
+    }
+}
+
+impl<T: IntegerCoeff> Sub for RationalFunction<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<T: IntegerCoeff> Mul for RationalFunction<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.numer * rhs.numer, self.denom * rhs.denom)
+    }
+}
+
+/// Panics if `rhs` is zero.
+impl<T: IntegerCoeff> Div for RationalFunction<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.numer.is_zero(), "division by zero");
+        Self::new(self.numer * rhs.denom, self.denom * rhs.numer)
+    }
+}
+
+impl<T: IntegerCoeff> Zero for RationalFunction<T> {
+    fn zero() -> Self {
+        RationalFunction {
+            numer: Poly::zero(),
+            denom: Poly::one(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+}
+
+impl<T: IntegerCoeff> One for RationalFunction<T> {
+    fn one() -> Self {
+        RationalFunction {
+            numer: Poly::one(),
+            denom: Poly::one(),
+        }
+    }
+}
+
+impl<T: SignedCoeff> fmt::Display for RationalFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denom.is_one() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "({}) / ({})", self.numer, self.denom)
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::division::{from_polys_with, pdiv};
+use crate::mpoly::MPoly;
+use crate::{Base, DetEntry, ExactCoeff, Poly, Sym};
+use num_traits::{One, Zero};
+
+impl<T: ExactCoeff> Poly<T> {
+    /// Resultant with respect to `var`, computed by the subresultant polynomial remainder sequence.
+    ///
+    /// Equals the determinant of the Sylvester matrix of the two polynomials,
+    /// it vanishes exactly when they have a common factor of positive degree in `var`.
+    pub fn resultant(&self, other: &Poly<T>, var: &Sym) -> Poly<T> {
+        let (polys, vars, x) = from_polys_with(&[self, other], var);
+        match resultant(&polys[0], &polys[1], x) {
+            Some(res) => res.to_poly(&vars),
+            None => DetEntry::determinant(sylvester(&polys[0], &polys[1], x, &vars)),
+        }
+    }
+
+    /// Discriminant with respect to `var`, `(-1)^(n (n - 1) / 2) res(f, f') / lc(f)`
+    /// for a polynomial `f` of degree `n`.
+    pub fn discriminant(&self, var: &Sym) -> Poly<T> {
+        let (polys, vars, x) = from_polys_with(&[self], var);
+        let f = &polys[0];
+        let n = f.degree(x);
+        if f.is_zero() || n < 1 {
+            return Poly::zero();
+        }
+        let derivative = f.derivative(x);
+        let res = match resultant(f, &derivative, x).and_then(|res| res.div_exact(&f.lc_in(x))) {
+            Some(res) => res.to_poly(&vars),
+            None => {
+                // lc(f) only occurs in the first column of the Sylvester matrix,
+                // as lc(f) in the first row and n lc(f) in the first row of f'
+                let mut rows = sylvester(f, &derivative, x, &vars);
+                let first = n as usize - 1;
+                rows[0][0] = Poly::one();
+                rows[first][0] = (0..n).fold(Poly::zero(), |sum, _| sum + Poly::one());
+                DetEntry::determinant(rows)
+            }
+        };
+        if n * (n - 1) / 2 % 2 == 1 {
+            -res
+        } else {
+            res
+        }
+    }
+}
+
+fn pow<T: ExactCoeff>(f: &MPoly<T>, n: i32) -> MPoly<T> {
+    let mut result = MPoly::constant(f.nvars, T::one());
+    for _ in 0..n {
+        result = result.mul(f);
+    }
+    result
+}
+
+/// Resultant of `a` and `b` with respect to variable `x`,
+/// `None` if overflowing coefficients break the exact divisions.
+pub(crate) fn resultant<T: ExactCoeff>(a: &MPoly<T>, b: &MPoly<T>, x: usize) -> Option<MPoly<T>> {
+    if a.is_zero() || b.is_zero() {
+        return Some(MPoly::constant(a.nvars, T::zero()));
+    }
+    let (mut a, mut b) = (a.clone(), b.clone());
+    let mut sign = false;
+    if a.degree(x) < b.degree(x) {
+        std::mem::swap(&mut a, &mut b);
+        sign = a.degree(x) % 2 == 1 && b.degree(x) % 2 == 1;
+    }
+    let mut g = MPoly::constant(a.nvars, T::one());
+    let mut h = g.clone();
+    while b.degree(x) > 0 {
+        let (m, n) = (a.degree(x), b.degree(x));
+        let delta = m - n;
+        if m % 2 == 1 && n % 2 == 1 {
+            sign = !sign;
+        }
+        let (_, r) = pdiv(&a, &b, x);
+        if r.is_zero() {
+            return Some(r);
+        }
+        a = b;
+        b = r.div_exact(&g.mul(&pow(&h, delta)))?;
+        g = a.lc_in(x);
+        // h = g^delta / h^(delta - 1)
+        h = pow(&g, delta).div_exact(&pow(&h, delta - 1))?;
+    }
+    // b is free of x, the last subresultant is b^m / h^(m - 1)
+    let m = a.degree(x);
+    let result = if m == 0 {
+        MPoly::constant(a.nvars, T::one())
+    } else {
+        pow(&b, m).div_exact(&pow(&h, m - 1))?
+    };
+    Some(if sign { result.neg() } else { result })
+}
+
+/// Rows of the Sylvester matrix of `a` and `b` with respect to `x`.
+fn sylvester<T: ExactCoeff>(
+    a: &MPoly<T>,
+    b: &MPoly<T>,
+    x: usize,
+    vars: &[Base<T>],
+) -> Vec<Vec<Poly<T>>> {
+    let (m, n) = (a.degree(x) as usize, b.degree(x) as usize);
+    let dense = |f: &MPoly<T>, degree: usize| {
+        let mut coeffs = vec![Poly::zero(); degree + 1];
+        for (power, coeff) in f.coeffs_in(x) {
+            coeffs[degree - power as usize] = coeff.to_poly(vars);
+        }
+        coeffs
+    };
+    let (a, b) = (dense(a, m), dense(b, n));
+    let row = |coeffs: &[Poly<T>], shift: usize| {
+        (0..m + n)
+            .map(|j| {
+                j.checked_sub(shift)
+                    .and_then(|k| coeffs.get(k))
+                    .cloned()
+                    .unwrap_or_else(Poly::zero)
+            })
+            .collect()
+    };
+    (0..n)
+        .map(|i| row(&a, i))
+        .chain((0..m).map(|i| row(&b, i)))
+        .collect()
+}
@@ -0,0 +1,205 @@
+use crate::matrix::{bareiss_polys, partial_pivot, pivot_tolerance};
+use crate::mpoly::MPoly;
+use crate::{Field, IntegerCoeff, Matrix, Poly, RationalFunction, Vector};
+use array_init::array_init;
+use num_traits::Zero;
+use std::fmt;
+
+/// Error of solving a linear system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+    /// infinitely many solutions
+    Singular,
+    /// no solution
+    Inconsistent,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Singular => write!(f, "singular system, the solution is not unique"),
+            Self::Inconsistent => write!(f, "inconsistent system, no solution"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// LU decomposition with partial pivoting, `P A = L U`.
+///
+/// Computed once by `Matrix::lu`, it solves the system for any number of right-hand sides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lu<T, const R: usize, const C: usize> {
+    /// `U` in the pivot rows from the pivots on, and below each pivot the multipliers
+    /// of the unit lower triangular `L`.
+    pub matrix: Matrix<T, R, C>,
+    /// Row `i` of `P A` is row `permutation[i]` of `A`.
+    pub permutation: [usize; R],
+    /// Column of the pivot in each of the first `rank` rows.
+    pub pivots: Vec<usize>,
+}
+
+impl<T: Field, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Gaussian elimination with partial pivoting.
+    ///
+    /// Pivots of rounded entries below the size of the rounding error count as zero.
+    pub fn lu(&self) -> Lu<T, R, C> {
+        let mut rows = (0..R)
+            .map(|r| self.row(r).data.to_vec())
+            .collect::<Vec<_>>();
+        let tolerance = pivot_tolerance(rows.iter().flatten(), R.max(C));
+        let mut permutation = array_init(|i| i);
+        let mut pivots = Vec::new();
+        for k in 0..C {
+            let rank = pivots.len();
+            if rank == R {
+                break;
+            }
+            let Some(pivot) = partial_pivot(&rows, rank, k, tolerance) else {
+                for row in &mut rows[rank..] {
+                    row[k] = T::zero();
+                }
+                continue;
+            };
+            rows.swap(pivot, rank);
+            permutation.swap(pivot, rank);
+            let (top, bottom) = rows.split_at_mut(rank + 1);
+            let pivot_row = &top[rank];
+            for row in bottom {
+                let factor = row[k].clone() / pivot_row[k].clone();
+                for (value, above) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                    *value = value.clone() - factor.clone() * above.clone();
+                }
+                row[k] = factor;
+            }
+            pivots.push(k);
+        }
+        Lu {
+            matrix: Matrix {
+                data: array_init(|c| array_init(|r| rows[r][c].clone())),
+            },
+            permutation,
+            pivots,
+        }
+    }
+
+    /// Solves `self * x = b` through `Matrix::lu`.
+    pub fn solve(&self, b: &Vector<T, R>) -> Result<Vector<T, C>, SolveError> {
+        self.lu().solve(b)
+    }
+}
+
+impl<T: Field, const R: usize, const C: usize> Lu<T, R, C> {
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// Solves `A x = b` by forward and back substitution.
+    pub fn solve(&self, b: &Vector<T, R>) -> Result<Vector<T, C>, SolveError> {
+        let entries = &self.matrix.data;
+        let tolerance = pivot_tolerance(entries.iter().flatten().chain(&b.data), R.max(C));
+        let mut y = self.permutation.map(|i| b.data[i].clone());
+        for (s, &k) in self.pivots.iter().enumerate() {
+            for i in s + 1..R {
+                y[i] = y[i].clone() - entries[k][i].clone() * y[s].clone();
+            }
+        }
+        if y[self.rank()..]
+            .iter()
+            .any(|value| !value.is_zero() && value.magnitude() > tolerance)
+        {
+            return Err(SolveError::Inconsistent);
+        }
+        if self.rank() < C {
+            return Err(SolveError::Singular);
+        }
+        // full column rank, the pivot of row i is in column i
+        let mut x = vec![T::zero(); C];
+        for i in (0..C).rev() {
+            let mut value = y[i].clone();
+            for j in i + 1..C {
+                value = value - entries[j][i].clone() * x[j].clone();
+            }
+            x[i] = value / entries[i][i].clone();
+        }
+        Ok(Vector {
+            data: array_init(|i| x[i].clone()),
+        })
+    }
+}
+
+impl<T: Field, const N: usize> Lu<T, N, N> {
+    /// Product of the pivots, negated for an odd permutation.
+    pub fn determinant(&self) -> T {
+        if self.rank() < N {
+            return T::zero();
+        }
+        let det = (0..N).fold(T::one(), |det, i| det * self.matrix.data[i][i].clone());
+        // each cycle of length `l` takes `l - 1` swaps
+        let mut seen = [false; N];
+        let mut swaps = 0;
+        for start in 0..N {
+            let mut i = start;
+            let mut length = 0usize;
+            while !seen[i] {
+                seen[i] = true;
+                i = self.permutation[i];
+                length += 1;
+            }
+            swaps += length.saturating_sub(1);
+        }
+        if swaps % 2 == 0 {
+            det
+        } else {
+            T::zero() - det
+        }
+    }
+}
+
+impl<T: IntegerCoeff, const N: usize> Matrix<Poly<T>, N, N> {
+    /// Solves `self * x = b` by back substitution in the fraction-free echelon form of
+    /// the augmented matrix, or by `Matrix::lu` over rational functions if the fixed-width
+    /// coefficients overflow.
+    pub fn solve(
+        &self,
+        b: &Vector<Poly<T>, N>,
+    ) -> Result<Vector<RationalFunction<T>, N>, SolveError> {
+        let rows = (0..N)
+            .map(|r| {
+                let mut row = self.row(r).data.to_vec();
+                row.push(b.data[r].clone());
+                row
+            })
+            .collect::<Vec<_>>();
+        let Some((rows, vars, rank, _)) = bareiss_polys(&rows, false) else {
+            // overflowing coefficients, eliminate over rational functions instead
+            let m = Matrix {
+                data: self.data.clone().map(|col| col.map(RationalFunction::from)),
+            };
+            return m.solve(&Vector {
+                data: b.data.clone().map(RationalFunction::from),
+            });
+        };
+        if rows
+            .iter()
+            .any(|row| row[..N].iter().all(MPoly::is_zero) && !row[N].is_zero())
+        {
+            return Err(SolveError::Inconsistent);
+        }
+        if rank < N {
+            return Err(SolveError::Singular);
+        }
+        let entry = |r: usize, c: usize| RationalFunction::from(rows[r][c].to_poly(&vars));
+        let mut x = vec![RationalFunction::zero(); N];
+        for i in (0..N).rev() {
+            let mut value = entry(i, N);
+            for (j, known) in x.iter().enumerate().skip(i + 1) {
+                value = value - entry(i, j) * known.clone();
+            }
+            x[i] = value / entry(i, i);
+        }
+        Ok(Vector {
+            data: array_init(|i| x[i].clone()),
+        })
+    }
+}
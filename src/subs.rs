@@ -0,0 +1,97 @@
+use crate::{Base, Coeff, Der, EvalError, Factor, Mono, Poly};
+use std::collections::HashMap;
+
+/// Substitute inside the parameters of functions and nested polynomials.
+fn subs_base<T: Coeff>(
+    base: &Base<T>,
+    map: &HashMap<Base<T>, Poly<T>>,
+) -> Result<Base<T>, EvalError> {
+    Ok(match base {
+        Base::Sym(_) => base.clone(),
+        Base::Der(der) => Base::Der(Der {
+            func: der.func.clone(),
+            param: der.param.subs(map)?,
+        }),
+        Base::Poly(poly) => Base::Poly(poly.subs(map)?),
+    })
+}
+
+/// Multiply `term` by `value^power`, keeping `value` as a `Base::Poly` factor unless it is a
+/// single term that can be raised to the power, or a sum to expand for a positive power.
+fn mul_power<T: Coeff>(
+    term: &mut Mono<T>,
+    sums: &mut Vec<Poly<T>>,
+    value: &Poly<T>,
+    power: i32,
+) -> Result<(), EvalError> {
+    match value.terms.as_slice() {
+        [mono] if power > 0 || mono.coeff.is_one() || (-mono.coeff.clone()).is_one() => {
+            for _ in 0..power.unsigned_abs() {
+                term.coeff = term.coeff.clone() * mono.coeff.clone();
+            }
+            term.factors
+                .extend(mono.factors.iter().map(|factor| Factor {
+                    base: factor.base.clone(),
+                    power: factor.power * power,
+                }));
+        }
+        [] if power < 0 => return Err(EvalError::DivisionByZero),
+        [] if power > 0 => term.coeff = T::zero(),
+        _ if power == 1 => sums.push(value.clone()),
+        _ if power > 0 => sums.push(value.pow(power as u32)),
+        _ => term.factors.push(Factor {
+            base: Base::Poly(value.clone()),
+            power,
+        }),
+    }
+    Ok(())
+}
+
+impl<T: Coeff> Poly<T> {
+    /// Replace symbols, function applications or any other bases by the polynomials they map to,
+    /// e.g. `sinθ -> s`, then merge like terms.
+    ///
+    /// A base is matched before and after substituting inside it.
+    /// Fails with `EvalError::DivisionByZero` when a base under a negative power maps to zero.
+    pub fn subs(&self, map: &HashMap<Base<T>, Poly<T>>) -> Result<Poly<T>, EvalError> {
+        let mut terms = vec![];
+        for term in &self.terms {
+            let mut mono = Mono {
+                coeff: term.coeff.clone(),
+                factors: vec![],
+            };
+            let mut sums = vec![];
+            for factor in &term.factors {
+                let value = match map.get(&factor.base) {
+                    Some(value) => Some(value),
+                    None => {
+                        let base = subs_base(&factor.base, map)?;
+                        let value = map.get(&base);
+                        if value.is_none() {
+                            mono.factors.push(Factor {
+                                base,
+                                power: factor.power,
+                            });
+                        }
+                        value
+                    }
+                };
+                if let Some(value) = value {
+                    mul_power(&mut mono, &mut sums, value, factor.power)?;
+                }
+            }
+            if mono.coeff.is_zero() {
+                continue;
+            }
+            mono.merge_factors();
+            let mut product = Poly::from(mono);
+            for sum in sums {
+                product *= sum;
+            }
+            terms.extend(product.terms);
+        }
+        let mut result = Poly { terms };
+        result.merge_terms();
+        Ok(result)
+    }
+}
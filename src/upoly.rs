@@ -0,0 +1,642 @@
+//! Dense univariate polynomials over the integers and over small prime fields,
+//! `f[i]` is the coefficient of `x^i`.
+
+use num_bigint::BigInt;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+const PRIMES: [u64; 40] = [
+    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179,
+];
+
+/// Mersenne prime `2^31 - 1`, used to rule out candidate factors cheaply.
+const CHECK_PRIME: u64 = (1 << 31) - 1;
+
+/// Simple xorshift generator, deterministic so that results are reproducible.
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub fn new() -> Self {
+        Rng(0x2545_f491_4f6c_dd1d)
+    }
+
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+pub(crate) fn trim<C: PartialEq + Default>(f: &mut Vec<C>) {
+    while f.last().is_some_and(|c| *c == C::default()) {
+        f.pop();
+    }
+}
+
+fn degree<C>(f: &[C]) -> usize {
+    f.len().saturating_sub(1)
+}
+
+/*
+ *
+ * Polynomials over the integers.
+ *
+ */
+
+fn int_gcd(a: &BigInt, b: &BigInt) -> BigInt {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while !b.is_zero() {
+        let r = &a % &b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+pub(crate) fn content(f: &[BigInt]) -> BigInt {
+    f.iter().fold(BigInt::zero(), |acc, c| int_gcd(&acc, c))
+}
+
+/// Primitive part with positive leading coefficient.
+pub(crate) fn primitive(f: &[BigInt]) -> Vec<BigInt> {
+    let mut cont = content(f);
+    if cont.is_zero() {
+        return vec![];
+    }
+    if f[f.len() - 1].is_negative() {
+        cont = -cont;
+    }
+    f.iter().map(|c| c / &cont).collect()
+}
+
+pub(crate) fn derivative(f: &[BigInt]) -> Vec<BigInt> {
+    f.iter().enumerate().skip(1).map(|(i, c)| c * i).collect()
+}
+
+fn sub(f: &[BigInt], g: &[BigInt]) -> Vec<BigInt> {
+    let mut result = vec![BigInt::zero(); f.len().max(g.len())];
+    for (i, c) in f.iter().enumerate() {
+        result[i] = c.clone();
+    }
+    for (i, c) in g.iter().enumerate() {
+        result[i] -= c;
+    }
+    trim(&mut result);
+    result
+}
+
+pub(crate) fn mul(f: &[BigInt], g: &[BigInt]) -> Vec<BigInt> {
+    let mut result = vec![BigInt::zero(); f.len() + g.len() - 1];
+    for (i, a) in f.iter().enumerate() {
+        for (j, b) in g.iter().enumerate() {
+            result[i + j] += a * b;
+        }
+    }
+    result
+}
+
+/// Exact quotient of `f` divided by `g`, `None` if `g` does not divide `f` over the integers.
+pub(crate) fn div_exact(f: &[BigInt], g: &[BigInt]) -> Option<Vec<BigInt>> {
+    let mut r = f.to_vec();
+    if r.len() < g.len() {
+        return if r.is_empty() { Some(vec![]) } else { None };
+    }
+    let mut q = vec![BigInt::zero(); r.len() - g.len() + 1];
+    let lc = &g[g.len() - 1];
+    while !r.is_empty() {
+        if r.len() < g.len() {
+            return None;
+        }
+        let c = &r[r.len() - 1] / lc;
+        if &c * lc != r[r.len() - 1] {
+            return None;
+        }
+        let shift = r.len() - g.len();
+        for (i, value) in g.iter().enumerate() {
+            r[shift + i] -= value * &c;
+        }
+        q[shift] = c;
+        trim(&mut r);
+    }
+    Some(q)
+}
+
+fn is_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+/// Largest prime below `n`.
+fn prime_below(n: u64) -> u64 {
+    (2..n).rev().find(|&n| is_prime(n)).unwrap()
+}
+
+/// Primitive part of the greatest common divisor, computed modulo several large primes
+/// and combined by the Chinese remainder theorem until the candidate divides both inputs.
+pub(crate) fn gcd(f: &[BigInt], g: &[BigInt]) -> Vec<BigInt> {
+    let a = primitive(f);
+    let b = primitive(g);
+    if a.is_empty() || b.is_empty() {
+        return if a.is_empty() { b } else { a };
+    }
+    let scale = int_gcd(&a[a.len() - 1], &b[b.len() - 1]);
+    let mut p = 1 << 31;
+    let mut modulus = BigInt::one();
+    let mut image: Vec<BigInt> = vec![];
+    loop {
+        p = prime_below(p);
+        let s = to_mod(&scale, p);
+        if s == 0 {
+            continue;
+        }
+        let gp = fp_gcd(&reduce(&a, p), &reduce(&b, p), p);
+        if gp.len() == 1 {
+            return vec![BigInt::one()];
+        }
+        let gp = gp.iter().map(|c| mul_p(*c, s, p)).collect::<Vec<_>>();
+        if image.is_empty() || gp.len() < image.len() {
+            // unlucky primes give images of too high degree
+            image = gp.iter().map(|c| BigInt::from(*c)).collect();
+            modulus = BigInt::from(p);
+        } else if gp.len() == image.len() {
+            let inv = inv_mod(to_mod(&modulus, p), p);
+            for (c, r) in image.iter_mut().zip(&gp) {
+                // c + modulus * ((r - c) / modulus mod p)
+                let d = mul_p((r + p - to_mod(c, p)) % p, inv, p);
+                *c += &modulus * d;
+            }
+            modulus *= p;
+        } else {
+            continue;
+        }
+        let candidate = primitive(
+            &image
+                .iter()
+                .map(|c| symmetric(c, &modulus))
+                .collect::<Vec<_>>(),
+        );
+        if div_exact(&a, &candidate).is_some() && div_exact(&b, &candidate).is_some() {
+            return candidate;
+        }
+    }
+}
+
+/// Square-free decomposition of a primitive polynomial by Yun's algorithm,
+/// returns the square-free factors with their multiplicities.
+pub(crate) fn square_free(f: &[BigInt]) -> Vec<(Vec<BigInt>, u32)> {
+    let df = derivative(f);
+    let a = gcd(f, &df);
+    let mut b = div_exact(f, &a).unwrap();
+    let c = div_exact(&df, &a).unwrap();
+    let mut d = sub(&c, &derivative(&b));
+    let mut result = vec![];
+    let mut power = 1;
+    while b.len() > 1 {
+        let a = gcd(&b, &d);
+        b = div_exact(&b, &a).unwrap();
+        let c = div_exact(&d, &a).unwrap();
+        d = sub(&c, &derivative(&b));
+        if a.len() > 1 {
+            result.push((a, power));
+        }
+        power += 1;
+    }
+    result
+}
+
+/*
+ *
+ * Polynomials over the prime field Z/pZ.
+ *
+ */
+
+pub(crate) fn mul_p(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, p: u64) -> u64 {
+    let mut result = 1;
+    base %= p;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_p(result, base, p);
+        }
+        base = mul_p(base, base, p);
+        exp >>= 1;
+    }
+    result
+}
+
+pub(crate) fn inv_mod(a: u64, p: u64) -> u64 {
+    pow_mod(a, p - 2, p)
+}
+
+/// Residue of `c` in `0..p`.
+pub(crate) fn to_mod(c: &BigInt, p: u64) -> u64 {
+    (c % p).to_i128().unwrap().rem_euclid(p as i128) as u64
+}
+
+pub(crate) fn reduce(f: &[BigInt], p: u64) -> Vec<u64> {
+    let mut result = f.iter().map(|c| to_mod(c, p)).collect::<Vec<_>>();
+    trim(&mut result);
+    result
+}
+
+fn fp_monic(f: &[u64], p: u64) -> Vec<u64> {
+    let inv = inv_mod(f[f.len() - 1], p);
+    f.iter().map(|c| mul_p(*c, inv, p)).collect()
+}
+
+pub(crate) fn fp_add(f: &[u64], g: &[u64], p: u64) -> Vec<u64> {
+    let mut result = vec![0; f.len().max(g.len())];
+    for (i, c) in f.iter().enumerate() {
+        result[i] = *c;
+    }
+    for (i, c) in g.iter().enumerate() {
+        result[i] = (result[i] + c) % p;
+    }
+    trim(&mut result);
+    result
+}
+
+pub(crate) fn fp_sub(f: &[u64], g: &[u64], p: u64) -> Vec<u64> {
+    let neg = g.iter().map(|c| (p - c) % p).collect::<Vec<_>>();
+    fp_add(f, &neg, p)
+}
+
+pub(crate) fn fp_mul(f: &[u64], g: &[u64], p: u64) -> Vec<u64> {
+    if f.is_empty() || g.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0; f.len() + g.len() - 1];
+    for (i, a) in f.iter().enumerate() {
+        for (j, b) in g.iter().enumerate() {
+            result[i + j] = (result[i + j] + mul_p(*a, *b, p)) % p;
+        }
+    }
+    trim(&mut result);
+    result
+}
+
+pub(crate) fn fp_divrem(f: &[u64], g: &[u64], p: u64) -> (Vec<u64>, Vec<u64>) {
+    let mut r = f.to_vec();
+    if r.len() < g.len() {
+        return (vec![], r);
+    }
+    let mut q = vec![0; r.len() - g.len() + 1];
+    let inv = inv_mod(g[g.len() - 1], p);
+    while r.len() >= g.len() {
+        let shift = r.len() - g.len();
+        let c = mul_p(r[r.len() - 1], inv, p);
+        q[shift] = c;
+        for (i, value) in g.iter().enumerate() {
+            r[shift + i] = (r[shift + i] + p - mul_p(*value, c, p)) % p;
+        }
+        trim(&mut r);
+    }
+    (q, r)
+}
+
+fn fp_rem(f: &[u64], g: &[u64], p: u64) -> Vec<u64> {
+    fp_divrem(f, g, p).1
+}
+
+/// Monic greatest common divisor.
+pub(crate) fn fp_gcd(f: &[u64], g: &[u64], p: u64) -> Vec<u64> {
+    let mut a = f.to_vec();
+    let mut b = g.to_vec();
+    while !b.is_empty() {
+        let r = fp_rem(&a, &b, p);
+        a = b;
+        b = r;
+    }
+    if a.is_empty() {
+        a
+    } else {
+        fp_monic(&a, p)
+    }
+}
+
+/// Returns `(s, t)` with `s f + t g = 1`, `f` and `g` must be coprime.
+pub(crate) fn fp_xgcd(f: &[u64], g: &[u64], p: u64) -> (Vec<u64>, Vec<u64>) {
+    let (mut r0, mut r1) = (f.to_vec(), g.to_vec());
+    let (mut s0, mut s1) = (vec![1], vec![]);
+    let (mut t0, mut t1) = (vec![], vec![1]);
+    while !r1.is_empty() {
+        let (q, r) = fp_divrem(&r0, &r1, p);
+        let s = fp_sub(&s0, &fp_mul(&q, &s1, p), p);
+        let t = fp_sub(&t0, &fp_mul(&q, &t1, p), p);
+        r0 = std::mem::replace(&mut r1, r);
+        s0 = std::mem::replace(&mut s1, s);
+        t0 = std::mem::replace(&mut t1, t);
+    }
+    let inv = inv_mod(r0[0], p);
+    (
+        s0.iter().map(|c| mul_p(*c, inv, p)).collect(),
+        t0.iter().map(|c| mul_p(*c, inv, p)).collect(),
+    )
+}
+
+fn fp_powmod(base: &[u64], mut exp: u64, modulus: &[u64], p: u64) -> Vec<u64> {
+    let mut result = vec![1];
+    let mut base = fp_rem(base, modulus, p);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = fp_rem(&fp_mul(&result, &base, p), modulus, p);
+        }
+        base = fp_rem(&fp_mul(&base, &base, p), modulus, p);
+        exp >>= 1;
+    }
+    result
+}
+
+fn fp_derivative(f: &[u64], p: u64) -> Vec<u64> {
+    let mut result = f
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| mul_p(*c, i as u64 % p, p))
+        .collect::<Vec<_>>();
+    trim(&mut result);
+    result
+}
+
+/// Distinct-degree factorization of a monic square-free polynomial.
+fn distinct_degree(f: &[u64], p: u64) -> Vec<(Vec<u64>, usize)> {
+    let mut result = vec![];
+    let mut f = f.to_vec();
+    let x = vec![0, 1];
+    let mut h = x.clone();
+    let mut d = 1;
+    while degree(&f) >= 2 * d {
+        h = fp_powmod(&h, p, &f, p);
+        let g = fp_gcd(&fp_sub(&h, &x, p), &f, p);
+        if g.len() > 1 {
+            f = fp_divrem(&f, &g, p).0;
+            h = fp_rem(&h, &f, p);
+            result.push((g, d));
+        }
+        d += 1;
+    }
+    if f.len() > 1 {
+        let d = degree(&f);
+        result.push((f, d));
+    }
+    result
+}
+
+/// Cantor-Zassenhaus splitting of a product of irreducible factors of degree `d`.
+fn equal_degree(f: &[u64], d: usize, p: u64, rng: &mut Rng) -> Vec<Vec<u64>> {
+    if degree(f) == d {
+        return vec![f.to_vec()];
+    }
+    loop {
+        let mut a = (0..degree(f)).map(|_| rng.next() % p).collect::<Vec<_>>();
+        trim(&mut a);
+        if a.len() < 2 {
+            continue;
+        }
+        // a^((p^d - 1) / 2) = (a^(1 + p + ... + p^(d-1)))^((p - 1) / 2)
+        let mut t = a.clone();
+        let mut acc = a;
+        for _ in 1..d {
+            t = fp_powmod(&t, p, f, p);
+            acc = fp_rem(&fp_mul(&acc, &t, p), f, p);
+        }
+        let b = fp_powmod(&acc, (p - 1) / 2, f, p);
+        let g = fp_gcd(&fp_sub(&b, &[1], p), f, p);
+        if g.len() > 1 && g.len() < f.len() {
+            let h = fp_divrem(f, &g, p).0;
+            let mut result = equal_degree(&g, d, p, rng);
+            result.extend(equal_degree(&h, d, p, rng));
+            return result;
+        }
+    }
+}
+
+/// Monic irreducible factors of a square-free polynomial modulo `p`.
+fn factor_mod_p(f: &[u64], p: u64, rng: &mut Rng) -> Vec<Vec<u64>> {
+    let f = fp_monic(f, p);
+    let mut result = vec![];
+    for (g, d) in distinct_degree(&f, p) {
+        result.extend(equal_degree(&g, d, p, rng));
+    }
+    result
+}
+
+/*
+ *
+ * Hensel lifting modulo p^k.
+ *
+ */
+
+/// Residue of `c` in `0..m`.
+fn modulo(c: &BigInt, m: &BigInt) -> BigInt {
+    let r = c % m;
+    if r.is_negative() {
+        r + m
+    } else {
+        r
+    }
+}
+
+/// Residue of `c` in `-m/2..=m/2`.
+fn symmetric(c: &BigInt, m: &BigInt) -> BigInt {
+    let c = modulo(c, m);
+    if c > m / 2u32 {
+        c - m
+    } else {
+        c
+    }
+}
+
+fn inv_mod_m(a: &BigInt, m: &BigInt) -> BigInt {
+    let (mut r0, mut r1) = (m.clone(), a.clone());
+    let (mut t0, mut t1) = (BigInt::zero(), BigInt::one());
+    while !r1.is_zero() {
+        let q = &r0 / &r1;
+        let r = &r0 - &q * &r1;
+        let t = &t0 - &q * &t1;
+        (r0, r1) = (r1, r);
+        (t0, t1) = (t1, t);
+    }
+    modulo(&t0, m)
+}
+
+fn mod_mul_poly(f: &[BigInt], g: &[BigInt], m: &BigInt) -> Vec<BigInt> {
+    mul(f, g).iter().map(|c| modulo(c, m)).collect()
+}
+
+/// Lift `f = g h (mod p)` to a factorization modulo `m = p^k`, `g` is monic.
+fn lift_pair(
+    f: &[BigInt],
+    g: &[u64],
+    h: &[u64],
+    p: u64,
+    k: u32,
+    m: &BigInt,
+) -> (Vec<BigInt>, Vec<BigInt>) {
+    let (_, t) = fp_xgcd(g, h, p);
+    let mut big_g = g.iter().map(|c| BigInt::from(*c)).collect::<Vec<_>>();
+    let mut big_h = h.iter().map(|c| BigInt::from(*c)).collect::<Vec<_>>();
+    *big_h.last_mut().unwrap() = f[f.len() - 1].clone();
+    let mut pj = BigInt::from(p);
+    for _ in 1..k {
+        let product = mod_mul_poly(&big_g, &big_h, m);
+        let mut e = f
+            .iter()
+            .zip(product)
+            .map(|(a, b)| to_mod(&((a - b) / &pj), p))
+            .collect::<Vec<_>>();
+        trim(&mut e);
+        // e = (e s) g + (e t) h, reduce e t modulo g to keep g monic
+        let dg = fp_rem(&fp_mul(&e, &t, p), g, p);
+        let dh = fp_divrem(&fp_sub(&e, &fp_mul(&dg, h, p), p), g, p).0;
+        for (i, c) in dg.iter().enumerate() {
+            big_g[i] = modulo(&(&big_g[i] + &pj * c), m);
+        }
+        for (i, c) in dh.iter().enumerate() {
+            big_h[i] = modulo(&(&big_h[i] + &pj * c), m);
+        }
+        pj *= p;
+    }
+    (big_g, big_h)
+}
+
+/// Lift the monic factors of `f (mod p)` to monic factors modulo `p^k`.
+fn hensel_lift(f: &[BigInt], factors: &[Vec<u64>], p: u64, k: u32) -> (Vec<Vec<BigInt>>, BigInt) {
+    let m = BigInt::from(p).pow(k);
+    let mut current = f.iter().map(|c| modulo(c, &m)).collect::<Vec<_>>();
+    let lc = to_mod(&f[f.len() - 1], p);
+    let mut lifted = vec![];
+    for (i, g) in factors.iter().enumerate().take(factors.len() - 1) {
+        let h = factors[i + 1..]
+            .iter()
+            .fold(vec![lc], |acc, factor| fp_mul(&acc, factor, p));
+        let (big_g, big_h) = lift_pair(&current, g, &h, p, k, &m);
+        lifted.push(big_g);
+        current = big_h;
+    }
+    let inv = inv_mod_m(&current[current.len() - 1], &m);
+    lifted.push(current.iter().map(|c| modulo(&(c * &inv), &m)).collect());
+    (lifted, m)
+}
+
+/// Irreducible factors of a primitive square-free polynomial with positive leading coefficient,
+/// found by modular factorization, Hensel lifting and recombination of the lifted factors.
+pub(crate) fn factor_square_free(f: &[BigInt]) -> Vec<Vec<BigInt>> {
+    if f.len() <= 2 {
+        return vec![f.to_vec()];
+    }
+    let mut rng = Rng::new();
+    let lc = &f[f.len() - 1];
+    // try a few suitable primes and keep the one giving the fewest modular factors,
+    // only finitely many primes divide the leading coefficient or the discriminant
+    let primes = PRIMES
+        .into_iter()
+        .chain((PRIMES[PRIMES.len() - 1] + 2..).filter(|&n| is_prime(n)));
+    let mut best: Option<(u64, Vec<Vec<u64>>)> = None;
+    let mut tried = 0;
+    for p in primes {
+        if to_mod(lc, p) == 0 {
+            continue;
+        }
+        let fp = reduce(f, p);
+        if fp_gcd(&fp, &fp_derivative(&fp, p), p).len() > 1 {
+            continue;
+        }
+        let factors = factor_mod_p(&fp, p, &mut rng);
+        if best.as_ref().is_none_or(|(_, b)| factors.len() < b.len()) {
+            best = Some((p, factors));
+        }
+        tried += 1;
+        if tried == 5 {
+            break;
+        }
+    }
+    let (p, factors) = best.unwrap();
+    if factors.len() == 1 {
+        return vec![f.to_vec()];
+    }
+
+    // Mignotte bound for the coefficients of a factor, scaled by the leading coefficient
+    let n = degree(f) as f64;
+    let norm = f.iter().map(|c| c.bits()).max().unwrap() as f64;
+    let bound = 0.5 * (n + 1.0).log2() + n + norm + lc.bits() as f64 + 1.0;
+    let k = (bound / (p as f64).log2()).ceil() as u32 + 1;
+    let (lifted, m) = hensel_lift(f, &factors, p, k);
+
+    let mut remained = (0..lifted.len()).collect::<Vec<_>>();
+    let mut result = vec![];
+    let mut f = f.to_vec();
+    let mut size = 1;
+    'outer: while 2 * size <= remained.len() {
+        let lc = modulo(&f[f.len() - 1], &m);
+        let trailing = &lc * &f[0];
+        let f_q = reduce(&f, CHECK_PRIME);
+        let mut subset = (0..size).collect::<Vec<_>>();
+        loop {
+            // the constant term of a true factor divides the one of lc f
+            let constant = subset.iter().fold(lc.clone(), |acc, &i| {
+                modulo(&(acc * &lifted[remained[i]][0]), &m)
+            });
+            let constant = symmetric(&constant, &m);
+            if trailing.is_zero() || (!constant.is_zero() && (&trailing % &constant).is_zero()) {
+                let product = subset.iter().fold(vec![lc.clone()], |acc, &i| {
+                    mod_mul_poly(&acc, &lifted[remained[i]], &m)
+                });
+                let candidate =
+                    primitive(&product.iter().map(|c| symmetric(c, &m)).collect::<Vec<_>>());
+                // a true factor also divides modulo another prime, which is cheaper to test
+                // than the exact division with its fast growing quotients
+                let candidate_q = reduce(&candidate, CHECK_PRIME);
+                let possible = candidate_q.len() < candidate.len()
+                    || fp_divrem(&f_q, &candidate_q, CHECK_PRIME).1.is_empty();
+                if let Some(quotient) = possible.then(|| div_exact(&f, &candidate)).flatten() {
+                    result.push(candidate);
+                    f = quotient;
+                    for &i in subset.iter().rev() {
+                        remained.remove(i);
+                    }
+                    continue 'outer;
+                }
+            }
+            if !next_subset(&mut subset, remained.len()) {
+                break;
+            }
+        }
+        size += 1;
+    }
+    result.push(f);
+    result
+}
+
+/// Advance to the next subset of the same size in lexicographic order.
+pub(crate) fn next_subset(subset: &mut [usize], n: usize) -> bool {
+    let k = subset.len();
+    for i in (0..k).rev() {
+        if subset[i] < n - k + i {
+            subset[i] += 1;
+            for j in i + 1..k {
+                subset[j] = subset[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+/// Complete factorization of a primitive polynomial with positive leading coefficient,
+/// returns irreducible factors with their multiplicities.
+pub(crate) fn factor(f: &[BigInt]) -> Vec<(Vec<BigInt>, u32)> {
+    let mut result = vec![];
+    for (g, power) in square_free(f) {
+        for factor in factor_square_free(&g) {
+            result.push((factor, power));
+        }
+    }
+    result
+}
@@ -63,8 +63,8 @@ impl<T: MulAssign + Clone, const D: usize> Mul<T> for Vector<T, D> {
 
     fn mul(self, rhs: T) -> Self::Output {
         let mut data = self.data;
-        for i in 0..D {
-            data[i] *= rhs.clone();
+        for value in data.iter_mut() {
+            *value *= rhs.clone();
         }
         Vector { data }
     }
@@ -75,8 +75,8 @@ impl<T: MulAssign + Clone, const D: usize> Mul<T> for &Vector<T, D> {
 
     fn mul(self, rhs: T) -> Self::Output {
         let mut data = self.data.clone();
-        for i in 0..D {
-            data[i] *= rhs.clone();
+        for value in data.iter_mut() {
+            *value *= rhs.clone();
         }
         Vector { data }
     }
@@ -87,8 +87,8 @@ impl<T: Neg<Output = T> + Clone, const D: usize> Neg for Vector<T, D> {
 
     fn neg(self) -> Self::Output {
         let mut data = self.data;
-        for i in 0..D {
-            data[i] = -data[i].clone();
+        for value in data.iter_mut() {
+            *value = -value.clone();
         }
         Vector { data }
     }
@@ -99,8 +99,8 @@ impl<T: Neg<Output = T> + Clone, const D: usize> Neg for &Vector<T, D> {
 
     fn neg(self) -> Self::Output {
         let mut data = self.data.clone();
-        for i in 0..D {
-            data[i] = -data[i].clone();
+        for value in data.iter_mut() {
+            *value = -value.clone();
         }
         Vector { data }
     }
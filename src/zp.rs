@@ -0,0 +1,196 @@
+use crate::Coeff;
+use num_traits::{FromPrimitive, Num, One, Zero};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Integer modulo the prime `P`, an element of the field GF(P).
+///
+/// The value is kept in `0..P`. Division by zero panics. `P` must be prime for division
+/// to be correct, a modulus below 2 fails to compile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Zp<const P: u64>(u64);
+
+impl<const P: u64> Zp<P> {
+    const MODULUS: u64 = {
+        assert!(P >= 2, "the modulus of `Zp` must be a prime");
+        P
+    };
+
+    pub fn new(value: i64) -> Self {
+        Zp((value as i128).rem_euclid(Self::MODULUS as i128) as u64)
+    }
+
+    /// Representative in `0..P`.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Multiplicative inverse by Fermat's little theorem, `None` for zero.
+    pub fn inv(self) -> Option<Self> {
+        (self.0 != 0).then(|| self.pow(P - 2))
+    }
+}
+
+impl<const P: u64> From<u64> for Zp<P> {
+    fn from(value: u64) -> Self {
+        Zp(value % Self::MODULUS)
+    }
+}
+
+impl<const P: u64> Default for Zp<P> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+/// Never negative, the value is written as its representative in `0..P`.
+impl<const P: u64> Coeff for Zp<P> {}
+
+impl<const P: u64> FromPrimitive for Zp<P> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Zp::new(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Zp::from(n))
+    }
+}
+
+impl<const P: u64> fmt::Display for Zp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Add for Zp<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Zp(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Zp<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<const P: u64> Mul for Zp<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Zp(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Div for Zp<P> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let inv = rhs.inv().expect("division by zero");
+        Mul::mul(self, inv)
+    }
+}
+
+/// Every division is exact in a field, the remainder is always zero.
+impl<const P: u64> Rem for Zp<P> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "division by zero");
+        Self::zero()
+    }
+}
+
+impl<const P: u64> Neg for Zp<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Zp((P - self.0) % P)
+    }
+}
+
+impl<const P: u64> AddAssign for Zp<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> SubAssign for Zp<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> MulAssign for Zp<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> DivAssign for Zp<P> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64> Zero for Zp<P> {
+    fn zero() -> Self {
+        Self::from(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for Zp<P> {
+    fn one() -> Self {
+        Zp(1 % Self::MODULUS)
+    }
+}
+
+/// Error of parsing a `Zp`, an invalid integer or a zero denominator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseZpError;
+
+impl fmt::Display for ParseZpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid modular integer")
+    }
+}
+
+impl std::error::Error for ParseZpError {}
+
+/// Parses integers, possibly negative, and fractions `n/d`.
+impl<const P: u64> Num for Zp<P> {
+    type FromStrRadixErr = ParseZpError;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let parse = |text: &str| {
+            i128::from_str_radix(text, radix)
+                .map(|n| Zp(n.rem_euclid(Self::MODULUS as i128) as u64))
+                .map_err(|_| ParseZpError)
+        };
+        match str.split_once('/') {
+            Some((numer, denom)) => Ok(parse(numer)? * parse(denom)?.inv().ok_or(ParseZpError)?),
+            None => parse(str),
+        }
+    }
+}
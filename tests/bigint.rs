@@ -0,0 +1,173 @@
+use mathguru::{Base, BigInt, Matrix, Poly, RationalFunction, Sym, ToLatex};
+#[cfg(feature = "bigint")]
+use mathguru::{BigRational, MonomialOrder};
+use std::collections::HashMap;
+
+#[test]
+fn test_bigint() {
+    let parse = |text: &str| Poly::<BigInt>::parse(text).unwrap();
+    // coefficients far beyond i64
+    let p = parse("x + 1000000000000").pow(4);
+    let expected = parse(
+        "x^4 + 4000000000000 x^3 + 6000000000000000000000000 x^2 \
+         + 4000000000000000000000000000000000000 x + 1000000000000000000000000000000000000000000000000",
+    );
+    assert_eq!(p, expected);
+
+    let q = parse("x + 1000000000000") * parse("x - 3");
+    assert_eq!(q.gcd(&p), parse("1000000000000 + x"));
+    assert_eq!((q / parse("x - 3")).unwrap(), parse("1000000000000 + x"));
+
+    let env = HashMap::from([(Sym::from("x"), 1.0)]);
+    assert_eq!(parse("2 x + 3").eval(&env), Ok(5.0));
+
+    let r = RationalFunction::new(parse("6 x^2 - 6"), parse("4 x + 4"));
+    assert_eq!(r, RationalFunction::new(parse("3 x - 3"), parse("2")));
+    assert_eq!(parse("- 1000000000000 x").to_latex(), "-1000000000000 x");
+}
+
+#[test]
+fn test_big_determinant() {
+    let n = |v: i64| BigInt::from(v);
+    let m = Matrix::<BigInt, 3, 3>::new(
+        n(4_000_000_000),
+        n(1),
+        n(2),
+        n(3),
+        n(4_000_000_000),
+        n(5),
+        n(7),
+        n(11),
+        n(4_000_000_000),
+    );
+    let big = n(4_000_000_000);
+    let expected = big.clone() * big.clone() * big.clone() - n(72) * big + n(101);
+    assert_eq!(m.determinant(), expected);
+}
+
+#[test]
+#[cfg(feature = "bigint")]
+fn test_big_rational() {
+    let p: Poly<BigRational> = Poly::parse("x^2 y + 2 y").unwrap();
+    let q: Poly<BigRational> = Poly::parse("x^2 + 2").unwrap();
+    assert_eq!((p / q).unwrap(), Poly::parse("y").unwrap());
+
+    // coefficients written as `n/d`
+    let r: Poly<BigRational> = Poly::parse("1/2 x + 1/2").unwrap();
+    let expected: Poly<BigRational> = Poly::parse("1/4 x^2 + 1/2 x + 1/4").unwrap();
+    assert_eq!(r.pow(2), expected);
+
+    // every nonzero coefficient divides in a field
+    let parse = |text: &str| Poly::<BigRational>::parse(text).unwrap();
+    assert_eq!(parse("x") / parse("2"), Some(parse("1/2 x")));
+    assert_eq!(
+        parse("x^2 - 1/4") / parse("2 x + 1"),
+        Some(parse("1/2 x - 1/4"))
+    );
+    let (quotients, remainder) = parse("x^2 + 1").divide(&[parse("3 x")], MonomialOrder::Lex);
+    assert_eq!(quotients, vec![parse("1/3 x")]);
+    assert_eq!(remainder, parse("1"));
+
+    let m = Matrix::<Poly<BigRational>, 3, 3>::new(
+        parse("1/2 x"),
+        parse("y"),
+        parse("1"),
+        parse("1"),
+        parse("1/2 x"),
+        parse("y"),
+        parse("y"),
+        parse("1"),
+        parse("1/2 x"),
+    );
+    let det = parse("1/8 x^3 + y^3 + 1 - 3/2 x y");
+    assert_eq!(m.determinant(), det);
+    assert_eq!(m.echelon_fraction_free().unwrap().determinant, Some(det));
+
+    // the subresultant divisions are field divisions
+    let x = Sym::from("x");
+    assert_eq!(
+        parse("1/2 x^2 - y").resultant(&parse("x - 1/3"), &x),
+        parse("1/18 - y")
+    );
+    assert_eq!(
+        parse("1/2 x^2 + 1/3 x - y").discriminant(&x),
+        parse("1/9 + 2 y")
+    );
+}
+
+#[test]
+fn test_big_factorize() {
+    let parse = |text: &str| Poly::<BigInt>::parse(text).unwrap();
+    // coefficients beyond i128
+    let p = parse("x y + 100000000000000000000000000000000000000000") * parse("x + y");
+    let (content, factors) = p.factorize();
+    assert_eq!(content, BigInt::from(1));
+    assert_eq!(factors.len(), 2);
+    let mut product = parse("1");
+    for factor in &factors {
+        let Base::Poly(base) = &factor.base else {
+            panic!("unexpected factor {}", factor);
+        };
+        product *= base.clone();
+    }
+    assert_eq!(product, p);
+}
+
+#[test]
+fn test_big_gcd() {
+    let parse = |text: &str| Poly::<BigInt>::parse(text).unwrap();
+    // coefficients beyond i128 in a multivariate common factor
+    let g = parse("x y + 100000000000000000000000000000000000000000 z");
+    let a = g.clone() * parse("x + y + 1");
+    let b = g.clone() * parse("x - y^2 z");
+    assert_eq!(a.gcd(&b), g);
+    assert_eq!(
+        (a.clone() * parse("300000000000000000000000000000000000000000")).gcd(&(b * parse("2"))),
+        g.clone() * parse("2")
+    );
+    assert_eq!(a.gcd(&parse("x - y")), parse("1"));
+}
+
+#[test]
+fn test_big_factorize_multivariate() {
+    let parse = |text: &str| Poly::<BigInt>::parse(text).unwrap();
+    let factors = [
+        parse("x y + 100000000000000000000000000000000000000000 z"),
+        parse("x^2 + 300000000000000000000000000000000000000000 y + z"),
+        parse("x + y z"),
+    ];
+    let p = factors.iter().fold(parse("1"), |acc, f| acc * f.clone());
+    let (content, found) = p.factorize();
+    assert_eq!(content, BigInt::from(1));
+    assert_eq!(found.len(), 3);
+    for factor in &found {
+        let Base::Poly(base) = &factor.base else {
+            panic!("unexpected factor {}", factor);
+        };
+        assert!(factors.contains(base), "unexpected factor {}", base);
+    }
+}
+
+#[test]
+fn test_big_rational_function() {
+    let parse = |text: &str| Poly::<BigInt>::parse(text).unwrap();
+    let g = parse("x y + 100000000000000000000000000000000000000000");
+    let r = RationalFunction::new(g.clone() * parse("x + 1"), g.clone() * parse("x - 1"));
+    assert_eq!(r, RationalFunction::new(parse("x + 1"), parse("x - 1")));
+
+    let s = RationalFunction::new(parse("1"), g.clone());
+    let t = RationalFunction::new(parse("x"), g.clone() * parse("y"));
+    assert_eq!(
+        s + t,
+        RationalFunction::new(parse("x + y"), g.clone() * parse("y"))
+    );
+}
+
+#[test]
+#[cfg(feature = "bigint")]
+fn test_big_rational_latex() {
+    let p: Poly<BigRational> = Poly::parse("1/2 x^2 - 3/4 y + 2").unwrap();
+    assert_eq!(p.to_latex(), "2 + \\frac{1}{2} x^{2} - \\frac{3}{4} y");
+    let half = BigRational::new(BigInt::from(-1), BigInt::from(2));
+    assert_eq!(half.to_latex(), "-\\frac{1}{2}");
+}
@@ -0,0 +1,82 @@
+use mathguru::{Matrix, Poly, Quat, Sym, Zp, F64};
+use num_traits::Zero;
+
+/// `det(t I - m)` by cofactor expansion.
+fn char_det<const N: usize>(m: &Matrix<Poly<i32>, N, N>) -> Poly<i32> {
+    let shifted = Matrix::<Poly<i32>, N, N> {
+        data: std::array::from_fn(|c| {
+            std::array::from_fn(|r| {
+                let diagonal = if r == c {
+                    Poly::from("t")
+                } else {
+                    Poly::zero()
+                };
+                diagonal - m.data[c][r].clone()
+            })
+        }),
+    };
+    shifted.determinant()
+}
+
+#[test]
+fn test_charpoly() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    let m = Matrix::<Poly<i32>, 2, 2>::new(parse("a"), parse("b"), parse("c"), parse("d"));
+    assert_eq!(
+        m.charpoly(Sym::from("t")),
+        parse("t^2 - a t - d t + a d - b c")
+    );
+
+    let names = ["a", "b", "c", "d", "e", "f", "g", "h", "k"];
+    let m = Matrix::<Poly<i32>, 3, 3> {
+        data: std::array::from_fn(|c| std::array::from_fn(|r| parse(names[r * 3 + c]))),
+    };
+    assert_eq!(m.charpoly(Sym::from("t")), char_det(&m));
+
+    let m = Matrix::<i32, 4, 4>::new(2, -1, 0, 3, 1, 0, 4, -2, 0, 5, 1, 1, -3, 2, 2, 0);
+    let p = Matrix::<Poly<i32>, 4, 4> {
+        data: m.data.map(|col| col.map(|value| parse(&value.to_string()))),
+    };
+    assert_eq!(m.charpoly(Sym::from("t")), char_det(&p));
+    assert_eq!(
+        Matrix::<i32, 1, 1>::new(7).charpoly(Sym::from("t")),
+        parse("t - 7")
+    );
+
+    // over GF(5), x^2 + 1 = (x - 2)(x - 3)
+    let z = Zp::<5>::new;
+    let m = Matrix::<Zp<5>, 2, 2>::new(z(0), z(-1), z(1), z(0));
+    let expected: Poly<Zp<5>> = Poly::parse("t^2 + 1").unwrap();
+    assert_eq!(m.charpoly(Sym::from("t")), expected);
+
+    // floats give `F64` coefficients
+    let expected: Poly<F64> = Poly::parse("t^2 - 5 t - 2").unwrap();
+    let m = Matrix::<f64, 2, 2>::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(m.charpoly(Sym::from("t")), expected);
+    let m = Matrix::<f32, 2, 2>::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(m.charpoly(Sym::from("t")), expected);
+    let m = Matrix::<f64, 2, 2>::new(0.5, 0.0, 0.0, 0.5);
+    let expected: Poly<F64> = Poly::parse("t^2 - t + 0.25").unwrap();
+    assert_eq!(m.charpoly(Sym::from("t")), expected);
+}
+
+#[test]
+fn test_rotation_invariants() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    let q = Quat::<Poly<i32>>::new(parse("w"), parse("x"), parse("y"), parse("z"));
+    let r = (q.left_mul_matrix() * q.conjugate().right_mul_matrix()).block::<3, 3>(1, 1);
+    let p = r.charpoly(Sym::from("t"));
+    assert_eq!(p, char_det(&r));
+
+    // the coefficient of t^2 is minus the trace, the constant is minus the determinant
+    let n = parse("w^2 + x^2 + y^2 + z^2");
+    let trace = parse("3 w^2 - x^2 - y^2 - z^2");
+    let mut expected =
+        parse("t^3") - trace.clone() * parse("t^2") + n.clone() * trace * parse("t") - n.pow(3);
+    expected.expand();
+    expected.merge_terms();
+    let mut p = p;
+    p.expand();
+    p.merge_terms();
+    assert_eq!(p, expected);
+}
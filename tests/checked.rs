@@ -0,0 +1,52 @@
+use mathguru::{OverflowError, Poly};
+
+#[test]
+fn test_checked() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    let p: Poly<i32> = parse("x + 46340");
+    let mut square = p.clone() * p.clone();
+    square.merge_terms();
+    assert_eq!(p.try_mul(&p), Ok(square.clone()));
+    assert_eq!(p.try_pow(2), Ok(square));
+    assert_eq!(p.try_add(&p), Ok(parse("92680 + 2 x")));
+    assert_eq!(p.try_sub(&p), Ok(parse("0")));
+
+    let q: Poly<i32> = parse("x + 46341");
+    let error = q.try_mul(&q).unwrap_err();
+    assert_eq!(
+        error,
+        OverflowError {
+            operation: "*",
+            lhs: "46341".to_string(),
+            rhs: Some("46341".to_string()),
+        }
+    );
+    assert_eq!(error.to_string(), "coefficient overflow in 46341 * 46341");
+}
+
+#[test]
+fn test_checked_merge() {
+    let parse = |text: &str| Poly::<i8>::parse(text).unwrap();
+    // 100 x + 100 x overflows i8 when like terms are merged
+    let p: Poly<i8> = parse("100 x + y");
+    let q: Poly<i8> = parse("100 x - y");
+    let error = p.try_add(&q).unwrap_err();
+    assert_eq!(error.to_string(), "coefficient overflow in 100 + 100");
+    assert!(p.try_sub(&q).is_ok());
+
+    let mut r: Poly<i8> = parse("x");
+    r.terms.extend(parse("127 x").terms);
+    let before = r.clone();
+    assert!(r.try_merge_terms().is_err());
+    assert_eq!(r, before);
+
+    let mut s: Poly<i8> = parse("x");
+    s.terms[0].coeff = i8::MIN;
+    assert_eq!(
+        s.try_neg().unwrap_err().to_string(),
+        "coefficient overflow in --128"
+    );
+    // squaring gives (x + 1)^2 (x + 1)^8, where 2 * 70 overflows
+    assert_eq!(parse("x + 1").try_pow(10).unwrap_err().operation, "*");
+    assert_eq!(parse("x + 1").try_pow(7), Ok(parse("x + 1").pow(7)));
+}
@@ -0,0 +1,73 @@
+use mathguru::{Matrix, Poly, Zp};
+
+fn from_fn<T, const N: usize>(f: impl Fn(usize, usize) -> T) -> Matrix<T, N, N> {
+    Matrix {
+        data: std::array::from_fn(|c| std::array::from_fn(|r| f(r, c))),
+    }
+}
+
+#[test]
+fn test_determinant_sizes() {
+    // upper triangular times unit lower triangular, det is the product of the diagonal
+    fn product<const N: usize>() -> Matrix<i64, N, N> {
+        let l = from_fn::<i64, N>(|r, c| match r.cmp(&c) {
+            std::cmp::Ordering::Less => 0,
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Greater => (r * 3 + c) as i64 % 5 - 2,
+        });
+        let u = from_fn::<i64, N>(|r, c| match r.cmp(&c) {
+            std::cmp::Ordering::Greater => 0,
+            std::cmp::Ordering::Equal => r as i64 % 3 + 1,
+            std::cmp::Ordering::Less => (r + 2 * c) as i64 % 7 - 3,
+        });
+        &l * &u
+    }
+    assert_eq!(product::<8>().determinant(), 2 * 3 * 2 * 3 * 2);
+    assert_eq!(product::<9>().determinant(), 2 * 3 * 2 * 3 * 2 * 3);
+    assert_eq!(product::<1>().determinant(), 1);
+    assert_eq!(Matrix::<i64, 0, 0> { data: [] }.determinant(), 1);
+
+    // Bareiss agrees with cofactor expansion of the same entries as polynomials
+    let m = from_fn::<i64, 7>(|r, c| ((r * r + 3 * c + r * c) % 11) as i64 - 5);
+    let constant = |n: i64| Poly::parse(&n.to_string()).unwrap();
+    let p = from_fn::<Poly<i64>, 7>(|r, c| constant(m.data[c][r]));
+    assert_ne!(m.determinant(), 0);
+    assert_eq!(p.determinant(), constant(m.determinant()));
+}
+
+#[test]
+fn test_determinant_pivoting() {
+    // zero leading entries force row swaps
+    let m = Matrix::<i32, 3, 3>::new(0, 1, 0, 0, 0, 1, 1, 0, 0);
+    assert_eq!(m.determinant(), 1);
+    let m = Matrix::<i32, 3, 3>::new(0, 1, 0, 1, 0, 0, 0, 0, 1);
+    assert_eq!(m.determinant(), -1);
+    let singular = Matrix::<i32, 4, 4>::new(1, 2, 3, 4, 2, 4, 6, 8, 0, 1, 0, 1, 5, 6, 7, 8);
+    assert_eq!(singular.determinant(), 0);
+    assert_eq!(singular.minor(1, 1), 8);
+
+    let z = Zp::<7>::new;
+    let m = Matrix::<Zp<7>, 3, 3>::new(z(0), z(2), z(3), z(4), z(5), z(6), z(1), z(1), z(2));
+    assert_eq!(m.determinant(), z(-7));
+    let f = Matrix::<f64, 3, 3>::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0);
+    assert!((f.determinant() - 6.0).abs() < 1e-12);
+
+    // a tiny leading entry as pivot would cancel the whole determinant
+    let f = Matrix::<f64, 3, 3>::new(1e-17, 1.0, 1.0, 1.0, 1.0, 2.0, 1.0, 2.0, 1.0);
+    assert!((f.determinant() - 2.0).abs() < 1e-12);
+
+    // polynomial entries are divided exactly
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    let p = Matrix::<Poly<i32>, 3, 3>::new(
+        parse("x"),
+        parse("y"),
+        parse("1"),
+        parse("1"),
+        parse("x"),
+        parse("y"),
+        parse("y"),
+        parse("1"),
+        parse("x"),
+    );
+    assert_eq!(p.determinant(), parse("x^3 + y^3 + 1 - 3 x y"));
+}
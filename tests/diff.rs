@@ -0,0 +1,45 @@
+use mathguru::{Poly, Sym};
+
+fn diff(text: &str, var: &str) -> Poly<i32> {
+    Poly::<i32>::parse(text)
+        .unwrap()
+        .diff(&Sym::from(var.to_string()))
+}
+
+#[test]
+fn test_diff() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    assert_eq!(
+        diff("x^3 y + 5 x^-2 + y + 7", "x"),
+        parse("3 x^2 y - 10 x^-3")
+    );
+    assert_eq!(diff("x^3 y + 5 x^-2 + y + 7", "z"), parse("0"));
+    assert_eq!(diff("sin^2θ", "θ"), parse("2 sinθ cosθ"));
+    assert_eq!(diff("sinθ^2 + cosθ^2", "θ"), parse("0"));
+    assert_eq!(diff("(x + 1)^3 y", "x"), parse("3 (x + 1)^2 y"));
+    assert_eq!(diff("(x y + 1)^-1", "x"), parse("- y (x y + 1)^-2"));
+    assert_eq!(diff("exp(x^2)", "x"), parse("2 x exp(x^2)"));
+    assert_eq!(diff("cos(2 x)", "x"), parse("- 2 sin(2 x)"));
+    assert_eq!(diff("tan(x y)", "x"), parse("y cos(x y)^-2"));
+    assert_eq!(diff("ln(x^2 + 1)", "x"), parse("2 x (x^2 + 1)^-1"));
+    assert_eq!(diff("sqrt(x + 1)^2", "x"), parse("1"));
+    assert_eq!(diff("2 sqrt(x^2 + 1)", "x"), parse("2 x sqrt(x^2 + 1)^-1"));
+    assert_eq!(diff("sqrt(x)", "x"), parse("(2)^-1 sqrt(x)^-1"));
+
+    // unknown functions and nested applications
+    let f = Poly::<i32>::parse("x^2").unwrap().apply("f");
+    let expected = Poly::from(2) * "x".into() * Poly::<i32>::parse("x^2").unwrap().apply("f'");
+    assert_eq!(f.diff(&"x".into()), expected);
+    assert_eq!(diff("sin(cos(x))", "x"), parse("- cos(cos(x)) sin(x)"));
+}
+
+#[test]
+fn test_jacobian() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    // rotation of (a_x, a_y) by θ, derivative of the rotated x coordinate
+    let x = parse("a_x cosθ - a_y sinθ");
+    let y = parse("a_x sinθ + a_y cosθ");
+    assert_eq!(x.diff(&"θ".into()), -y.clone());
+    assert_eq!(y.diff(&"θ".into()), x.clone());
+    assert_eq!(x.diff(&"a_x".into()), parse("cosθ"));
+}
@@ -0,0 +1,67 @@
+use mathguru::{MonomialOrder, Poly};
+use num_traits::Zero;
+
+#[test]
+fn test_pseudo_division() {
+    let x: Poly<i32> = Poly::from("x");
+    let y: Poly<i32> = Poly::from("y");
+    let one: Poly<i32> = 1.into();
+
+    // a = x^3 y + x + 1, b = 2 y x^2 - 1
+    let a = x.clone() * x.clone() * x.clone() * y.clone() + x.clone() + one.clone();
+    let b = Poly::from(2) * y.clone() * x.clone() * x.clone() - one.clone();
+    let (q, r) = a.pdiv(&b, &"x".into());
+    let lc = Poly::from(2) * y.clone();
+    assert_eq!(
+        lc.clone() * lc.clone() * a.clone(),
+        q.clone() * b.clone() + r.clone()
+    );
+    assert_eq!(q, Poly::from(2) * x.clone() * y.clone() * y.clone());
+    assert_eq!(
+        r,
+        Poly::from(6) * x.clone() * y.clone() * y.clone() + Poly::from(4) * y.clone() * y.clone()
+    );
+    assert_eq!(a.prem(&b, &"x".into()), r);
+
+    // the remainder is the polynomial itself when its degree is lower
+    assert_eq!(b.pdiv(&a, &"x".into()), (Poly::zero(), b.clone()));
+}
+
+#[test]
+fn test_multivariate_division() {
+    let x: Poly<i32> = Poly::from("x");
+    let y: Poly<i32> = Poly::from("y");
+    let one: Poly<i32> = 1.into();
+
+    // x^2 y + x y^2 + y^2 divided by x y - 1 and y^2 - 1
+    let f = x.clone() * x.clone() * y.clone()
+        + x.clone() * y.clone() * y.clone()
+        + y.clone() * y.clone();
+    let g1 = x.clone() * y.clone() - one.clone();
+    let g2 = y.clone() * y.clone() - one.clone();
+    let (quotients, remainder) = f.divide(&[g1.clone(), g2.clone()], MonomialOrder::Lex);
+    assert_eq!(quotients, vec![x.clone() + y.clone(), one.clone()]);
+    assert_eq!(remainder, x.clone() + y.clone() + one.clone());
+
+    let (quotients, remainder) = f.divide(&[g2.clone(), g1.clone()], MonomialOrder::GrLex);
+    assert_eq!(
+        quotients[0].clone() * g2 + quotients[1].clone() * g1 + remainder.clone(),
+        f
+    );
+    assert_eq!(remainder, Poly::from(2) * x + one);
+}
+
+#[test]
+fn test_exact_division() {
+    let a: Poly<i32> = Poly::from("a");
+    let b: Poly<i32> = Poly::from("b");
+
+    let p = (a.clone() + b.clone()) * (a.clone() - b.clone()) * Poly::from(3);
+    assert_eq!(
+        p.clone() / (a.clone() - b.clone()),
+        Some(Poly::from(3) * (a.clone() + b.clone()))
+    );
+    assert_eq!(p.clone() / (a.clone() + Poly::from(2) * b.clone()), None);
+    assert_eq!(p.clone() / Poly::from(2), None);
+    assert_eq!(p / Poly::from(0), None);
+}
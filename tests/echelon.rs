@@ -0,0 +1,94 @@
+use mathguru::{Matrix, Poly};
+use num_traits::Zero;
+
+#[test]
+fn test_echelon_determinant() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    // Vandermonde, det = (b - a)(c - a)(d - a)(c - b)(d - b)(d - c)
+    let m = Matrix::<Poly<i32>, 4, 4>::new(
+        parse("1"),
+        parse("a"),
+        parse("a^2"),
+        parse("a^3"),
+        parse("1"),
+        parse("b"),
+        parse("b^2"),
+        parse("b^3"),
+        parse("1"),
+        parse("c"),
+        parse("c^2"),
+        parse("c^3"),
+        parse("1"),
+        parse("d"),
+        parse("d^2"),
+        parse("d^3"),
+    );
+    let echelon = m.echelon_fraction_free().unwrap();
+    assert_eq!(echelon.rank, 4);
+    let expected = parse("b - a")
+        * parse("c - a")
+        * parse("d - a")
+        * parse("c - b")
+        * parse("d - b")
+        * parse("d - c");
+    assert_eq!(echelon.determinant, Some(expected.clone()));
+    assert_eq!(m.determinant(), expected);
+
+    // row echelon form, zero below the pivots
+    for c in 0..4 {
+        for r in c + 1..4 {
+            assert!(echelon.matrix.data[c][r].is_zero());
+        }
+    }
+}
+
+#[test]
+fn test_echelon_rank() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    // third row is x times the first plus the second
+    let m = Matrix::<Poly<i32>, 3, 4>::new(
+        parse("0"),
+        parse("1"),
+        parse("y"),
+        parse("x"),
+        parse("0"),
+        parse("x"),
+        parse("2"),
+        parse("1"),
+        parse("0"),
+        parse("2 x"),
+        parse("x y + 2"),
+        parse("x^2 + 1"),
+    );
+    let echelon = m.echelon_fraction_free().unwrap();
+    assert_eq!(echelon.rank, 2);
+    assert_eq!(echelon.determinant, None);
+    assert_eq!(echelon.matrix.row(0), m.row(0));
+    assert_eq!(echelon.matrix.row(1).data[2], parse("2 - x y"));
+    assert!(echelon.matrix.row(2).data.iter().all(Zero::is_zero));
+
+    let singular = Matrix::<Poly<i32>, 2, 2>::new(parse("x"), parse("x y"), parse("1"), parse("y"));
+    assert_eq!(
+        singular.echelon_fraction_free().unwrap().determinant,
+        Some(parse("0"))
+    );
+
+    // rows swapped for the zero leading entry, the determinant agrees either way
+    let swapped = Matrix::<Poly<i32>, 3, 3>::new(
+        parse("0"),
+        parse("x"),
+        parse("1"),
+        parse("y"),
+        parse("1"),
+        parse("x y"),
+        parse("1"),
+        parse("0"),
+        parse("y"),
+    );
+    let determinant = parse("x^2 y - x y^2 - 1");
+    assert_eq!(
+        swapped.echelon_fraction_free().unwrap().determinant,
+        Some(determinant.clone())
+    );
+    assert_eq!(swapped.determinant(), determinant);
+}
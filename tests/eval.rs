@@ -0,0 +1,41 @@
+use mathguru::{EvalError, Poly, Sym};
+use std::collections::HashMap;
+
+fn env<F: Copy>(values: &[(&str, F)]) -> HashMap<Sym, F> {
+    values
+        .iter()
+        .map(|(name, value)| (Sym::from(name.to_string()), *value))
+        .collect()
+}
+
+#[test]
+fn test_eval() {
+    let p: Poly<i32> = Poly::parse("3 x^2 y - 2 x^-1 + (x + y)^2 + 5").unwrap();
+    let value = p.eval(&env(&[("x", 2.0), ("y", -1.0)])).unwrap();
+    assert_eq!(value, -12.0 - 1.0 + 1.0 + 5.0);
+
+    let q: Poly<i32> = Poly::parse("sin^2θ + cos^2θ + 2 sqrt(a^2 + 9)^-1").unwrap();
+    let value = q.eval(&env(&[("θ", 0.7), ("a", 4.0)])).unwrap();
+    assert!((value - 1.4).abs() < 1e-12);
+
+    let r: Poly<i64> = Poly::parse("ln(exp(t)) - t").unwrap();
+    let value = r.eval_in(&env(&[("t", 1.5f32)])).unwrap();
+    assert!(value.abs() < 1e-6);
+}
+
+#[test]
+fn test_eval_errors() {
+    let p: Poly<i32> = Poly::parse("x + y").unwrap();
+    assert_eq!(
+        p.eval(&env(&[("x", 1.0)])),
+        Err(EvalError::UnboundSymbol(Sym::from("y".to_string())))
+    );
+    let f = Poly::<i32>::from("x").apply("f");
+    let error = f.eval(&env(&[("x", 1.0)])).unwrap_err();
+    assert_eq!(error, EvalError::UnknownFunction("f".to_string()));
+    assert_eq!(error.to_string(), "unknown function `f`");
+
+    let q: Poly<i32> = Poly::parse("(x - 1)^-1").unwrap();
+    assert_eq!(q.eval(&env(&[("x", 1.0)])), Err(EvalError::DivisionByZero));
+    assert_eq!(q.eval(&env(&[("x", 3.0)])), Ok(0.5));
+}
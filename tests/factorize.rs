@@ -1,4 +1,4 @@
-use mathguru::Poly;
+use mathguru::{Base, Coeff, Factor, Matrix, Mono, Poly};
 
 #[test]
 fn test_factorize() {
@@ -17,3 +17,226 @@ fn test_factorize() {
     );
     println!("p={}", p);
 }
+
+fn product<T: Coeff>(content: T, factors: &[Factor<T>]) -> Poly<T> {
+    let mut result = Poly::from(Mono {
+        coeff: content,
+        factors: vec![],
+    });
+    for factor in factors {
+        let base = match &factor.base {
+            Base::Poly(poly) => poly.clone(),
+            Base::Sym(sym) => Poly::from(sym.clone()),
+            Base::Der(_) => unreachable!(),
+        };
+        for _ in 0..factor.power {
+            result *= base.clone();
+        }
+    }
+    result
+}
+
+#[test]
+fn test_factorize_univariate() {
+    let t: Poly<i32> = Poly::from("t");
+    let one: Poly<i32> = 1.into();
+
+    // -6 t (t - 2)^2 (t^2 + 1)
+    let p = Poly::from(-6)
+        * t.clone()
+        * (t.clone() - 2.into())
+        * (t.clone() - 2.into())
+        * (t.clone() * t.clone() + one.clone());
+    let (content, factors) = p.factorize();
+    assert_eq!(content, -6);
+    assert_eq!(factors.len(), 3);
+    assert_eq!(factors[0].base, Base::Sym("t".into()));
+    assert_eq!(
+        factors[1],
+        Factor {
+            base: Base::Poly(t.clone() - 2.into()),
+            power: 2
+        }
+    );
+    assert_eq!(product(content, &factors), p);
+
+    // t^8 - 1 = (t - 1) (t + 1) (t^2 + 1) (t^4 + 1)
+    let t2 = t.clone() * t.clone();
+    let t4 = t2.clone() * t2.clone();
+    let p = t4.clone() * t4.clone() - one.clone();
+    let (content, factors) = p.factorize();
+    assert_eq!(content, 1);
+    assert_eq!(factors.len(), 4);
+    assert!(factors.iter().all(|f| f.power == 1));
+    assert_eq!(product(content, &factors), p);
+
+    // irreducible over the integers, but reducible modulo every prime
+    let p = t4.clone() - t2.clone() * 10.into() + one.clone();
+    let (_, factors) = p.factorize();
+    assert_eq!(factors.len(), 1);
+
+    // (2 t + 3) (3 t^2 - 5)
+    let p = (t.clone() * 2.into() + 3.into()) * (t2.clone() * 3.into() - 5.into());
+    let (content, factors) = p.factorize();
+    assert_eq!(content, 1);
+    assert_eq!(factors.len(), 2);
+    assert_eq!(product(content, &factors), p);
+
+    // coefficient bound beyond 128 bits
+    let x: Poly<i64> = Poly::from("x");
+    let p = x.pow(120) - Poly::parse("1").unwrap();
+    let (content, factors) = p.factorize();
+    assert_eq!(content, 1);
+    // one cyclotomic factor per divisor of 120
+    assert_eq!(factors.len(), 16);
+    assert_eq!(product(content, &factors), p);
+}
+
+#[test]
+fn test_factorize_multivariate() {
+    let (a, b, c) = (Poly::from("a"), Poly::from("b"), Poly::from("c"));
+    let one: Poly<i32> = 1.into();
+
+    // Vandermonde determinant
+    let m = Matrix::<Poly<i32>, 3, 3>::new(
+        one.clone(),
+        one.clone(),
+        one.clone(),
+        a.clone(),
+        b.clone(),
+        c.clone(),
+        a.clone() * a.clone(),
+        b.clone() * b.clone(),
+        c.clone() * c.clone(),
+    );
+    let det = m.determinant();
+    let (content, factors) = det.factorize();
+    assert_eq!(content, -1);
+    assert_eq!(
+        factors,
+        vec![
+            Factor {
+                base: Base::Poly(a.clone() - b.clone()),
+                power: 1
+            },
+            Factor {
+                base: Base::Poly(a.clone() - c.clone()),
+                power: 1
+            },
+            Factor {
+                base: Base::Poly(b.clone() - c.clone()),
+                power: 1
+            },
+        ]
+    );
+
+    // -4 b (a + b + 1) (a^2 b - c + 3)^2 (a c - b^2)
+    let q = a.clone() * a.clone() * b.clone() - c.clone() + 3.into();
+    let p = Poly::from(-4)
+        * b.clone()
+        * (a.clone() + b.clone() + one.clone())
+        * q.clone()
+        * q.clone()
+        * (a.clone() * c.clone() - b.clone() * b.clone());
+    let (content, factors) = p.factorize();
+    assert_eq!(content, -4);
+    assert_eq!(factors.len(), 4);
+    assert!(factors.contains(&Factor {
+        base: Base::Poly(q),
+        power: 2
+    }));
+    assert_eq!(product(content, &factors), p);
+
+    // irreducible over the integers
+    let p = a.clone() * a.clone() * a.clone() * a.clone() + b.clone() * b.clone() * c.clone();
+    let (_, factors) = p.factorize();
+    assert_eq!(factors.len(), 1);
+}
+
+#[test]
+fn test_square_free() {
+    let x: Poly<i32> = Poly::from("x");
+    let y: Poly<i32> = Poly::from("y");
+    let one: Poly<i32> = 1.into();
+
+    // -3 y^2 (x - y) (x + 1)^2 x^2 (x^2 + y)^3
+    let p = x.clone() - y.clone();
+    let q = x.clone() + one.clone();
+    let r = x.clone() * x.clone() + y.clone();
+    let mut f = Poly::from(-3) * y.clone() * y.clone() * p.clone();
+    for _ in 0..2 {
+        f *= q.clone() * x.clone();
+    }
+    for _ in 0..3 {
+        f *= r.clone();
+    }
+    let (content, factors) = f.square_free(&"x".into());
+    assert_eq!(content, Poly::from(-3) * y.clone() * y.clone());
+    assert_eq!(
+        factors.iter().map(|f| f.power).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    let mut product = content;
+    for factor in &factors {
+        let Base::Poly(base) = &factor.base else {
+            panic!("unexpected factor {}", factor);
+        };
+        for _ in 0..factor.power {
+            product *= base.clone();
+        }
+    }
+    assert_eq!(product, f);
+    assert_eq!(factors[1].base, Base::Poly(x.clone() * q.clone()));
+
+    let (content, factors) = (x.clone() * y.clone()).square_free(&"z".into());
+    assert_eq!(content, x * y);
+    assert!(factors.is_empty());
+}
+
+#[test]
+fn test_factorize_large_coefficients() {
+    let parse = |text: &str| Poly::<i128>::parse(text).unwrap();
+
+    // coefficients of the lifted factors exceed the lifting modulus
+    let p = parse("x y + 100000000000000000000") * parse("x + y");
+    let (content, factors) = p.factorize();
+    assert_eq!(content, 1);
+    assert_eq!(factors.len(), 2);
+    assert_eq!(product(content, &factors), p);
+
+    let p = parse("a^2 b - 3000000000000000000000 c") * parse("a c + 7 b^2 + 5") * parse("a + b c");
+    let (content, factors) = p.factorize();
+    assert_eq!(content, 1);
+    assert_eq!(factors.len(), 3);
+    assert_eq!(product(content, &factors), p);
+}
+
+#[test]
+fn test_factorize_near_overflow() {
+    // the input and its factors fit in i32, the lifted and differentiated products do not
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    let g = parse("40000 x^2 + 3 y");
+    let h = parse("40000 x + 7 y^2 + 1");
+    let p = g.clone() * h.clone();
+    let (content, factors) = p.factorize();
+    assert_eq!(content, 1);
+    let mut bases = factors
+        .iter()
+        .map(|factor| factor.base.clone())
+        .collect::<Vec<_>>();
+    bases.sort();
+    let mut expected = vec![Base::Poly(g), Base::Poly(h)];
+    expected.sort();
+    assert_eq!(bases, expected);
+    assert_eq!(product(content, &factors), p);
+
+    let (content, factors) = p.square_free(&"x".into());
+    assert_eq!(content, parse("1"));
+    assert_eq!(
+        factors,
+        vec![Factor {
+            base: Base::Poly(p),
+            power: 1
+        }]
+    );
+}
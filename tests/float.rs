@@ -0,0 +1,72 @@
+use mathguru::{Matrix, Poly, Sym, F64};
+use std::collections::{HashMap, HashSet};
+
+#[test]
+fn test_f64_order() {
+    assert_eq!(F64(0.0), F64(-0.0));
+    assert_eq!(F64(f64::NAN), F64(f64::NAN));
+    assert!(F64(f64::INFINITY) < F64(f64::NAN));
+    assert!(F64(-1.5) < F64(0.0));
+    let set = HashSet::from([F64(0.0), F64(-0.0), F64(f64::NAN), F64(-f64::NAN)]);
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_f64_poly() {
+    let parse = |text: &str| Poly::<F64>::parse(text).unwrap();
+    // calibration constants plugged in, the measured quantity x stays free
+    let formula = parse("k x + c");
+    let map = HashMap::from([
+        (Sym::from("k").into(), parse("2.5")),
+        (Sym::from("c").into(), parse("-0.1")),
+    ]);
+    let mut calibrated = formula.subs(&map).unwrap();
+    calibrated.merge_terms();
+    assert_eq!(calibrated, parse("-0.1 + 2.5 x"));
+    assert_eq!(calibrated.to_string(), "- 0.1  + 2.5 x ");
+    assert_eq!(calibrated.diff(&Sym::from("x")), parse("2.5"));
+    assert_eq!(
+        parse("sqrt(x)").diff(&Sym::from("x")),
+        parse("0.5 sqrt(x)^-1")
+    );
+
+    let env = HashMap::from([(Sym::from("x"), 2.0)]);
+    assert_eq!(calibrated.eval(&env), Ok(4.9));
+}
+
+#[test]
+fn test_merge_terms_approx() {
+    let parse = |text: &str| Poly::<F64>::parse(text).unwrap();
+    // (x + 0.1)(x - 0.1) leaves rounding noise next to x^2 - 0.01
+    let mut p = parse("x + 0.1") * parse("x - 0.1") - parse("x^2") + parse("0.01") + parse("0.3 y");
+    let mut exact = p.clone();
+    exact.merge_terms();
+    assert_eq!(exact.terms.len(), 2);
+    let mut scaled = p.clone() * parse("1000000000");
+    p.merge_terms_approx(1e-12);
+    assert_eq!(p, parse("0.3 y"));
+
+    // the tolerance is relative to the largest coefficient
+    scaled.merge_terms_approx(1e-12);
+    assert_eq!(scaled, parse("300000000 y"));
+}
+
+#[test]
+fn test_f64_poly_determinant() {
+    // rounded coefficients use cofactor expansion instead of exact division
+    let parse = |text: &str| Poly::<F64>::parse(text).unwrap();
+    let m = Matrix::<Poly<F64>, 3, 3>::new(
+        parse("0.5 x"),
+        parse("y"),
+        parse("1"),
+        parse("1"),
+        parse("0.5 x"),
+        parse("y"),
+        parse("y"),
+        parse("1"),
+        parse("0.5 x"),
+    );
+    assert_eq!(m.determinant(), parse("0.125 x^3 + y^3 + 1 - 1.5 x y"));
+    let expected = parse("t^3 - 1.5 x t^2 + 0.75 x^2 t - 3 y t - 0.125 x^3 - y^3 - 1 + 1.5 x y");
+    assert_eq!(m.charpoly(Sym::from("t")), expected);
+}
@@ -0,0 +1,74 @@
+use mathguru::{
+    lookup_function, register_function, Base, Der, Derivative, Factor, Mono, Parity, Poly, Sym,
+    UnaryFunction,
+};
+
+#[test]
+fn test_builtin_functions() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    let x = Poly::<i32>::from("x");
+    assert_eq!((-x.clone()).apply("sin"), parse("- sin x"));
+    assert_eq!((-x.clone()).apply("cos"), parse("cos x"));
+    assert_eq!(parse("- x - y").apply("tan"), -parse("x + y").apply("tan"));
+    assert_eq!(parse("- x").apply("exp"), parse("exp(- x)"));
+    assert_eq!(Poly::<i32>::from(0).apply("cos"), parse("1"));
+    assert_eq!(Poly::<i32>::from(0).apply("sin"), parse("0"));
+    assert_eq!(Poly::<i32>::from(1).apply("ln"), parse("0"));
+    assert_eq!(Poly::<i32>::from(2).apply("ln"), parse("ln(2)"));
+    // without a declared parity the argument keeps its sign
+    let unknown = Poly::from(Mono {
+        coeff: 1,
+        factors: vec![Factor {
+            base: Base::Der(Der {
+                func: "f".into(),
+                param: parse("- x"),
+            }),
+            power: 1,
+        }],
+    });
+    assert_eq!(parse("- x").apply("f"), unknown);
+
+    let sin = lookup_function("sin").unwrap();
+    assert_eq!(sin.arity(), 1);
+    assert_eq!(sin.parity(), Parity::Odd);
+    assert_eq!(sin.eval(&[0.0]), 0.0);
+    assert!(lookup_function("undefined").is_none());
+}
+
+#[test]
+fn test_register_function() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    register_function(
+        UnaryFunction::new("sinh", f64::sinh)
+            .with_derivative("cosh(x)")
+            .with_parity(Parity::Odd)
+            .with_special_value(0, 0),
+    );
+    register_function(
+        UnaryFunction::new("cosh", f64::cosh)
+            .with_derivative("sinh(x)")
+            .with_parity(Parity::Even)
+            .with_special_value(0, 1),
+    );
+
+    let x = Poly::<i32>::from("x");
+    assert_eq!((-x.clone()).apply("sinh"), -x.apply("sinh"));
+    assert_eq!(Poly::<i32>::from(0).apply("cosh"), parse("1"));
+    assert_eq!(
+        parse("x^2").apply("sinh").diff(&Sym::from("x".to_string())),
+        parse("2 x") * parse("x^2").apply("cosh")
+    );
+    let cosh = lookup_function("cosh").unwrap();
+    assert!((cosh.eval(&[1.0]) - 1.0f64.cosh()).abs() < 1e-12);
+}
+
+#[test]
+fn test_derivative_parse() {
+    let derivative = Derivative::parse("(2)^-1 sqrt(x)^-1 - (3)^-1 x").unwrap();
+    assert_eq!(derivative.denominator, 6);
+    assert_eq!(
+        derivative.numerator,
+        Poly::parse("3 sqrt(x)^-1 - 2 x").unwrap()
+    );
+    assert!(Derivative::parse("cos(").is_err());
+}
@@ -0,0 +1,45 @@
+use mathguru::{Poly, RationalFunction};
+
+#[test]
+fn test_gcd() {
+    let a: Poly<i32> = Poly::from("a");
+    let b: Poly<i32> = Poly::from("b");
+    let c: Poly<i32> = Poly::from("c");
+    let one: Poly<i32> = 1.into();
+
+    // common factor 2 a (a b - c + 1)^2
+    let g = a.clone() * b.clone() - c.clone() + one.clone();
+    let p = Poly::from(4) * a.clone() * g.clone() * g.clone() * (a.clone() + b.clone());
+    let q =
+        Poly::from(-6) * a.clone() * a.clone() * g.clone() * g.clone() * (b.clone() - c.clone());
+    let expected = Poly::from(2) * a.clone() * g.clone() * g.clone();
+    assert_eq!(p.gcd(&q), expected);
+    assert_eq!(q.gcd(&p), expected);
+
+    // coprime inputs and the zero polynomial
+    let r = a.clone() * a.clone() + b.clone() * c.clone();
+    assert_eq!(r.gcd(&(a.clone() + c.clone())), one);
+    assert_eq!(r.gcd(&Poly::from(0)), r);
+    assert_eq!(Poly::from(12).gcd(&Poly::from(-18)), Poly::from(6));
+
+    // nested polynomials are expanded before taking the gcd
+    let s = (a.clone() - b.clone()) * (a.clone() + b.clone());
+    assert_eq!(
+        s.gcd(&(a.clone() * a.clone() - a.clone() * b.clone())),
+        a - b
+    );
+}
+
+#[test]
+fn test_gcd_near_overflow() {
+    // inputs and gcd fit in i32, the intermediate products do not
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    let g = parse("40000 x^2 + 3 y");
+    let p = g.clone() * parse("40000 x + 7 y^2 + 1");
+    let q = g.clone() * parse("30000 x - y + 5");
+    assert_eq!(p.gcd(&q), g);
+    assert_eq!(
+        RationalFunction::new(p, q),
+        RationalFunction::new(parse("40000 x + 7 y^2 + 1"), parse("30000 x - y + 5"))
+    );
+}
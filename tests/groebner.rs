@@ -0,0 +1,96 @@
+use mathguru::{GroebnerAlgorithm, GroebnerBasis, MonomialOrder, Poly};
+
+fn groebner(polys: &[Poly<i32>], order: MonomialOrder) -> Vec<Poly<i32>> {
+    let basis = GroebnerBasis::new(polys, order);
+    let f4 = GroebnerBasis::with_algorithm(polys, order, GroebnerAlgorithm::F4);
+    assert_eq!(basis, f4);
+    basis.polys
+}
+
+#[test]
+fn test_groebner_basis() {
+    let x: Poly<i32> = Poly::from("x");
+    let y: Poly<i32> = Poly::from("y");
+    let one: Poly<i32> = 1.into();
+
+    let circle = x.clone() * x.clone() + y.clone() * y.clone() - one.clone();
+    let line = x.clone() - y.clone();
+    assert_eq!(
+        groebner(&[circle.clone(), line.clone()], MonomialOrder::Lex),
+        vec![Poly::from(2) * y.clone() * y.clone() - one.clone(), line]
+    );
+
+    let f1 = x.clone() * x.clone() * x.clone() - Poly::from(2) * x.clone() * y.clone();
+    let f2 = x.clone() * x.clone() * y.clone() - Poly::from(2) * y.clone() * y.clone() + x.clone();
+    let expected = vec![
+        Poly::from(2) * y.clone() * y.clone() - x.clone(),
+        x.clone() * y.clone(),
+        x.clone() * x.clone(),
+    ];
+    assert_eq!(
+        groebner(&[f1.clone(), f2.clone()], MonomialOrder::GrLex),
+        expected
+    );
+    assert_eq!(
+        groebner(&[f1.clone(), f2.clone()], MonomialOrder::GrevLex),
+        expected
+    );
+
+    // cyclic 3-roots
+    let (a, b, c): (Poly<i32>, Poly<i32>, Poly<i32>) = ("a".into(), "b".into(), "c".into());
+    let cyclic = [
+        a.clone() + b.clone() + c.clone(),
+        a.clone() * b.clone() + b.clone() * c.clone() + c.clone() * a.clone(),
+        a.clone() * b.clone() * c.clone() - one.clone(),
+    ];
+    assert_eq!(
+        groebner(&cyclic, MonomialOrder::Lex),
+        vec![
+            c.clone() * c.clone() * c.clone() - one.clone(),
+            b.clone() * b.clone() + b.clone() * c.clone() + c.clone() * c.clone(),
+            a + b + c,
+        ]
+    );
+
+    // the constant polynomial generates the whole ring
+    assert_eq!(
+        groebner(
+            &[x.clone() * y.clone() - one.clone(), x.clone()],
+            MonomialOrder::Lex
+        ),
+        vec![one]
+    );
+}
+
+#[test]
+fn test_normal_form() {
+    let s: Poly<i32> = Poly::from("s");
+    let t: Poly<i32> = Poly::from("t");
+    let u: Poly<i32> = Poly::from("u");
+    let v: Poly<i32> = Poly::from("v");
+    let w: Poly<i32> = Poly::from("w");
+    let one: Poly<i32> = 1.into();
+
+    let constraints = [
+        u.clone() * u.clone() + v.clone() * v.clone() + w.clone() * w.clone() - one.clone(),
+        t.clone() * t.clone() + s.clone() * s.clone() - one.clone(),
+    ];
+    let basis = GroebnerBasis::new(&constraints, MonomialOrder::GrevLex);
+    assert_eq!(basis.polys.len(), 2);
+
+    // (t^2 + s^2) (u^2 + v^2 + w^2) w - s^2 w reduces to t^2 w
+    let f = (t.clone() * t.clone() + s.clone() * s.clone())
+        * (u.clone() * u.clone() + v.clone() * v.clone() + w.clone() * w.clone())
+        * w.clone()
+        - s.clone() * s.clone() * w.clone();
+    let (multiplier, remainder) = basis.normal_form(&f);
+    assert_eq!(multiplier, 1);
+    assert_eq!(basis.normal_form(&remainder), (1, remainder.clone()));
+    assert_eq!(
+        remainder,
+        basis.normal_form(&(t.clone() * t.clone() * w.clone())).1
+    );
+
+    assert!(basis.contains(&(constraints[0].clone() * s.clone() + constraints[1].clone() * v)));
+    assert!(!basis.contains(&(s * t)));
+}
@@ -0,0 +1,84 @@
+use mathguru::{Matrix, Poly, RationalFunction, Zp};
+
+type M2 = Matrix<RationalFunction<i64>, 2, 2>;
+
+fn identity<T: Clone, const N: usize>(zero: T, one: T) -> Matrix<T, N, N> {
+    Matrix {
+        data: std::array::from_fn(|c| {
+            std::array::from_fn(|r| if r == c { one.clone() } else { zero.clone() })
+        }),
+    }
+}
+
+#[test]
+fn test_adjugate() {
+    let parse = |text: &str| Poly::<i64>::parse(text).unwrap();
+    let m = Matrix::<Poly<i64>, 3, 3>::new(
+        parse("a"),
+        parse("b"),
+        parse("0"),
+        parse("c"),
+        parse("d"),
+        parse("1"),
+        parse("x"),
+        parse("0"),
+        parse("y"),
+    );
+    let det = m.determinant();
+    assert_eq!(det, parse("a d y - b c y + b x"));
+    let mut scaled = identity::<Poly<i64>, 3>(parse("0"), parse("1"));
+    for col in &mut scaled.data {
+        for value in col.iter_mut() {
+            *value = value.clone() * det.clone();
+        }
+    }
+    assert_eq!(&m * &m.adjugate(), scaled);
+    assert_eq!(&m.adjugate() * &m, scaled);
+
+    let one = Matrix::<i32, 1, 1>::new(5);
+    assert_eq!(one.adjugate(), Matrix::<i32, 1, 1>::new(1));
+    let m = Matrix::<i32, 2, 2>::new(1, 2, 3, 4);
+    assert_eq!(m.adjugate(), Matrix::<i32, 2, 2>::new(4, -2, -3, 1));
+}
+
+#[test]
+fn test_inverse() {
+    let parse = |text: &str| Poly::<i64>::parse(text).unwrap();
+    let z = Zp::<11>::new;
+    let m = Matrix::<Zp<11>, 3, 3>::new(z(2), z(0), z(1), z(1), z(3), z(2), z(1), z(1), z(2));
+    let inverse = m.inverse().unwrap();
+    assert_eq!(&m * &inverse, identity(z(0), z(1)));
+
+    let f = Matrix::<f64, 2, 2>::new(4.0, 7.0, 2.0, 4.0);
+    let inverse = f.inverse().unwrap();
+    assert_eq!(inverse, Matrix::<f64, 2, 2>::new(2.0, -3.5, -1.0, 2.0));
+    let singular = Matrix::<f64, 2, 2>::new(1.0, 2.0, 2.0, 4.0);
+    assert_eq!(singular.inverse(), None);
+
+    // zero and tiny leading entries need row swaps
+    let m = Matrix::<Zp<11>, 3, 3>::new(z(0), z(1), z(2), z(1), z(0), z(3), z(4), z(5), z(1));
+    assert_eq!(&m * &m.inverse().unwrap(), identity(z(0), z(1)));
+    let f = Matrix::<f64, 3, 3>::new(1e-18, 1.0, 2.0, 1.0, 1.0, 3.0, 4.0, 5.0, 1.0);
+    let product = &f * &f.inverse().unwrap();
+    for (c, col) in product.data.iter().enumerate() {
+        for (r, value) in col.iter().enumerate() {
+            let expected = if r == c { 1.0 } else { 0.0 };
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+
+    // inverse over rational functions, [[x, 1], [0, x]]^-1 = [[1/x, -1/x^2], [0, 1/x]]
+    let r = |numer: &str, denom: &str| RationalFunction::new(parse(numer), parse(denom));
+    let m = M2::new(r("x", "1"), r("1", "1"), r("0", "1"), r("x", "1"));
+    assert_eq!(
+        m.inverse(),
+        Some(M2::new(
+            r("1", "x"),
+            r("-1", "x^2"),
+            r("0", "1"),
+            r("1", "x")
+        ))
+    );
+    let singular = M2::new(r("x", "1"), r("1", "1"), r("x^2", "1"), r("x", "1"));
+    assert_eq!(singular.inverse(), None);
+}
@@ -0,0 +1,50 @@
+use mathguru::{Matrix, Poly, Quat, ToLatex, Vector3};
+
+#[test]
+fn test_latex() {
+    let p: Poly<i32> =
+        Poly::parse("- 2 a_x^2 b_y + sin^2θ + (u + v) w - cos(θ + ψ) + sqrt(x)^3 x^-1").unwrap();
+    assert_eq!(
+        p.to_latex(),
+        "-2 a_{x}^{2} b_{y} + w \\left(u + v\\right) + x^{-1} \\sqrt{x}^{3} - \\cos\\left(\\theta + \\psi\\right) + \\sin^{2} \\theta"
+    );
+    let f = Poly::<i32>::parse("t + 1").unwrap().apply("f");
+    assert_eq!(f.to_latex(), "\\operatorname{f}\\left(1 + t\\right)");
+    assert_eq!(Poly::<i32>::parse("0").unwrap().to_latex(), "0");
+    assert_eq!(Poly::<i32>::from("q_ω0").to_latex(), "q_{\\omega0}");
+    assert_eq!(Poly::<i32>::from("theta_1").to_latex(), "\\theta_{1}");
+    assert_eq!(Poly::<i32>::from("θψ").to_latex(), "\\theta\\psi");
+
+    let q: Poly<i32> = Poly::parse("a + b + c + d + e").unwrap();
+    assert_eq!(
+        q.latex().break_lines(2).to_string(),
+        "\\begin{aligned}\n& a + b \\\\\n& + c + d \\\\\n& + e\n\\end{aligned}"
+    );
+    assert_eq!(q.latex().break_lines(5).to_string(), "a + b + c + d + e");
+}
+
+#[test]
+fn test_latex_containers() {
+    let m = Matrix::<i32, 2, 2>::new(1, 2, 3, -4);
+    assert_eq!(
+        m.to_latex(),
+        "\\begin{bmatrix}\n1 & 2 \\\\\n3 & -4\n\\end{bmatrix}"
+    );
+
+    let v = Vector3::<Poly<i32>>::new("x".into(), "y".into(), Poly::from("x") - "y".into());
+    assert_eq!(
+        v.to_latex(),
+        "\\begin{bmatrix}\nx \\\\\ny \\\\\nx - y\n\\end{bmatrix}"
+    );
+
+    let q = Quat::<Poly<i32>>::new(
+        "q_0".into(),
+        -Poly::from("q_1"),
+        "q_2".into(),
+        Poly::from("a") + "b".into(),
+    );
+    assert_eq!(
+        q.latex().to_string(),
+        "q_{0} + \\left(-q_{1}\\right) \\mathbf{i} + q_{2} \\mathbf{j} + \\left(a + b\\right) \\mathbf{k}"
+    );
+}
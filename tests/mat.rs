@@ -1,4 +1,4 @@
-use mathguru::{Matrix, Poly, Quat, Vector3};
+use mathguru::{Base, Factor, Matrix, Poly, Quat, Vector3};
 type Vec3 = Vector3<Poly<i32>>;
 
 #[test]
@@ -29,12 +29,12 @@ fn test_determinant() {
     let r = (q.left_mul_matrix() * q.conjugate().right_mul_matrix()).block(1, 1);
     println!("{}", r);
 
-    let a = Vec3::new(format!("a_x").into(), format!("a_y").into(), 1.into());
-    let b = Vec3::new(format!("b_x").into(), format!("b_y").into(), 1.into());
-    let c = Vec3::new(format!("c_x").into(), format!("c_y").into(), 1.into());
-    let d = Vec3::new(format!("d_x").into(), format!("d_y").into(), 1.into());
-    let e = Vec3::new(format!("e_x").into(), format!("e_y").into(), 1.into());
-    let f = Vec3::new(format!("f_x").into(), format!("f_y").into(), 1.into());
+    let a = Vec3::new("a_x".into(), "a_y".into(), 1.into());
+    let b = Vec3::new("b_x".into(), "b_y".into(), 1.into());
+    let c = Vec3::new("c_x".into(), "c_y".into(), 1.into());
+    let d = Vec3::new("d_x".into(), "d_y".into(), 1.into());
+    let e = Vec3::new("e_x".into(), "e_y".into(), 1.into());
+    let f = Vec3::new("f_x".into(), "f_y".into(), 1.into());
 
     fn concat(u: Vec3, v: Vec3) -> [Poly<i32>; 6] {
         [u.x(), u.y(), u.z(), v.x(), v.y(), v.z()]
@@ -67,7 +67,9 @@ fn test_determinant() {
         + Poly::from("x") * Poly::from("x")
         + Poly::from("y") * Poly::from("y")
         + Poly::from("z") * Poly::from("z");
-    let mut det = m11.determinant().simplify_by_identity(constraint, 1.into());
+    let mut det = m11
+        .determinant()
+        .simplify_by_identity(constraint.clone(), 1.into());
     det.expand();
     dbg!(det.terms.len());
 
@@ -78,6 +80,13 @@ fn test_determinant() {
     let mut det = m.determinant();
     dbg!(det.terms.len());
 
+    let (content, factors) = det.factorize();
+    assert_eq!(content, 1);
+    assert!(factors.contains(&Factor {
+        base: Base::Poly(constraint.clone()),
+        power: 1
+    }));
+
     det.group_by(vec!["w".into(), "x".into(), "y".into(), "z".into()]);
     dbg!(det.terms.len());
     // println!("{}", det);
@@ -0,0 +1,87 @@
+use mathguru::{GroebnerBasis, MonomialOrder, Poly, TermOrder};
+
+#[test]
+fn test_monomial_orders() {
+    let x: Poly<i32> = Poly::from("x");
+    let y: Poly<i32> = Poly::from("y");
+    let z: Poly<i32> = Poly::from("z");
+    let one: Poly<i32> = 1.into();
+
+    // x^2 z + x y^2 + y z^2 + x + 1
+    let mut p = x.clone() * x.clone() * z.clone()
+        + x.clone() * y.clone() * y.clone()
+        + y.clone() * z.clone() * z.clone()
+        + x.clone()
+        + one.clone();
+
+    p.sort_terms(&MonomialOrder::Lex.into());
+    assert_eq!(p.to_string(), "x^2 z  + x y^2  + x  + y z^2  + 1 ");
+
+    let order = TermOrder::new(MonomialOrder::Lex, vec!["z".into(), "y".into()]);
+    p.sort_terms(&order);
+    assert_eq!(p.to_string(), "y z^2  + x^2 z  + x y^2  + x  + 1 ");
+    assert_eq!(p.leading_term(&order), Some(&p.terms[0]));
+
+    p.sort_terms(&MonomialOrder::GrLex.into());
+    assert_eq!(p.to_string(), "x^2 z  + x y^2  + y z^2  + x  + 1 ");
+
+    let order = MonomialOrder::GrevLex.into();
+    p.sort_terms(&order);
+    assert_eq!(p.to_string(), "x y^2  + x^2 z  + y z^2  + x  + 1 ");
+    assert_eq!(
+        p.leading_term(&order).unwrap().to_owned(),
+        (x.clone() * y.clone() * y.clone()).terms[0]
+    );
+
+    // y is greater than any monomial in x and z
+    let order = TermOrder::elimination(vec!["y".into()], MonomialOrder::GrevLex);
+    p.merge_terms_by(&order);
+    assert_eq!(p.to_string(), "x y^2  + y z^2  + x^2 z  + x  + 1 ");
+
+    let order = TermOrder::block(vec![
+        (vec!["z".into()], MonomialOrder::Lex),
+        (vec!["x".into(), "y".into()], MonomialOrder::GrLex),
+    ]);
+    p.sort_terms(&order);
+    assert_eq!(p.to_string(), "y z^2  + x^2 z  + x y^2  + x  + 1 ");
+}
+
+#[test]
+fn test_elimination() {
+    // implicitization of the curve x = t^2, y = t^3
+    let t: Poly<i32> = Poly::from("t");
+    let x: Poly<i32> = Poly::from("x");
+    let y: Poly<i32> = Poly::from("y");
+    let polys = [
+        x.clone() - t.clone() * t.clone(),
+        y.clone() - t.clone() * t.clone() * t.clone(),
+    ];
+    let basis = GroebnerBasis::new(
+        &polys,
+        TermOrder::elimination(vec!["t".into()], MonomialOrder::GrevLex),
+    );
+    let curve = y.clone() * y.clone() - x.clone() * x.clone() * x.clone();
+    let free = basis
+        .polys
+        .iter()
+        .filter(|poly| {
+            poly.terms
+                .iter()
+                .all(|term| term.power_of(&"t".into()) == 0)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(free.len(), 1);
+    assert!(*free[0] == curve || *free[0] == -curve);
+
+    // t^5 = -(t^3 + t x) (x - t^2) + t x^2
+    let t5 = t.clone() * t.clone() * t.clone() * t.clone() * t.clone();
+    let (quotients, remainder) =
+        t5.divide(&polys, TermOrder::new(MonomialOrder::Lex, vec!["t".into()]));
+    assert_eq!(remainder, t.clone() * x.clone() * x.clone());
+    assert_eq!(
+        quotients[0].clone() * polys[0].clone()
+            + quotients[1].clone() * polys[1].clone()
+            + remainder,
+        t5
+    );
+}
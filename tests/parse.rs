@@ -0,0 +1,109 @@
+use mathguru::{Base, Der, Factor, Mono, Poly, Vector3};
+
+type Vec3 = Vector3<Poly<i32>>;
+
+fn rotate(a: &Vec3, n: &Vec3, c: Poly<i32>, s: Poly<i32>) -> Vec3 {
+    let para = n * n.dot(a);
+    let perp = a - &para;
+    para + perp * c + n.cross(a) * s
+}
+
+fn round_trip(poly: &Poly<i32>) {
+    let text = poly.to_string();
+    let parsed: Poly<i32> = text.parse().unwrap();
+    // parsing normalizes the order of factors and terms
+    let mut expected = poly.clone();
+    expected.terms.iter_mut().for_each(Mono::merge_factors);
+    expected.merge_terms();
+    assert_eq!(parsed, expected, "{}", text);
+}
+
+#[test]
+fn test_parse() {
+    let p: Poly<i32> = Poly::parse("- 2 a_x^2 b_y + sin^2θ + (u + v) w").unwrap();
+    assert_eq!(p.terms.len(), 3);
+    assert_eq!(p.terms[0].coeff, -2);
+    assert_eq!(
+        p.terms[2].factors,
+        vec![Factor {
+            base: Base::Der(Der {
+                func: "sin".into(),
+                param: "θ".into(),
+            }),
+            power: 2,
+        }]
+    );
+    let u: Poly<i32> = "u".into();
+    let v: Poly<i32> = "v".into();
+    assert_eq!(
+        p.terms[1].factors[1].base,
+        Base::Poly(u.clone() + v.clone())
+    );
+
+    let a_x: Poly<i32> = "a_x".into();
+    let b_y: Poly<i32> = "b_y".into();
+    let sin = Poly::<i32>::from("θ").apply("sin");
+    let mut expected = Poly::from(-2) * a_x.clone() * a_x.clone() * b_y.clone()
+        + sin.clone() * sin
+        + Poly::from(Mono {
+            coeff: 1,
+            factors: vec![
+                Factor {
+                    base: Base::Poly(u + v),
+                    power: 1,
+                },
+                Factor {
+                    base: "w".into(),
+                    power: 1,
+                },
+            ],
+        });
+    expected.terms.iter_mut().for_each(Mono::merge_factors);
+    expected.merge_terms();
+    assert_eq!(p, expected);
+    round_trip(&p);
+
+    // like terms and factors are merged, negative powers and nested functions
+    let q: Poly<i32> = Poly::parse("x y x + 3 - 2 y x^2 + cos(x^-1 + 1)^3 - 3").unwrap();
+    assert_eq!(q.to_string(), "- x^2 y  + cos^3(1  + x^-1 ) ");
+    round_trip(&q);
+    assert_eq!(
+        Poly::<i32>::parse("0").unwrap(),
+        Poly::parse("x - x").unwrap()
+    );
+    assert!(Poly::<i32>::parse("0").unwrap().terms.is_empty());
+
+    // a function name joined to a symbol must end the ASCII word
+    let t: Poly<i32> = "t".into();
+    assert_eq!(Poly::<i32>::parse("cost").unwrap(), "cost".into());
+    assert_eq!(Poly::<i32>::parse("cos t").unwrap(), t.apply("cos"));
+    assert_eq!(Poly::<i32>::parse("cos(t)").unwrap(), t.apply("cos"));
+    assert_eq!(t.apply("cos").to_string(), "cos t  ");
+    round_trip(&(t.apply("sin") * Poly::from("θ").apply("cos")));
+
+    for text in ["", "x +", "(x + y", "x^", "sin", "x $"] {
+        assert!(Poly::<i32>::parse(text).is_err(), "{}", text);
+    }
+    let error = Poly::<i32>::parse("2 x + (y").unwrap_err();
+    assert_eq!(error.position, 8);
+}
+
+#[test]
+fn test_round_trip() {
+    let a = Vec3::new("a_x".into(), "a_y".into(), 1.into());
+    let n = Vec3::new("u".into(), "v".into(), "w".into());
+    let b = Vec3::new("b_x".into(), "b_y".into(), 1.into());
+    let mut rab = rotate(&a, &n, "c".into(), "s".into()).cross(&b);
+    for value in &rab.data {
+        round_trip(value);
+    }
+    for value in &mut rab.data {
+        value.group_by(vec!["u".into(), "v".into(), "w".into(), "c".into()]);
+        round_trip(value);
+    }
+
+    let theta: Poly<i32> = "θ".into();
+    let t = (theta.clone() + "ψ".into()).apply("cos") * theta.apply("sin")
+        - Poly::from(3) * Poly::from("ω").apply("exp");
+    round_trip(&t);
+}
@@ -15,7 +15,7 @@ fn rotate(a: &Vec3, n: &Vec3, c: Poly<i32>, s: Poly<i32>) -> Vec3 {
 fn create_normal(a: &str, b: &str, n: &Vec3, c: Poly<i32>, s: Poly<i32>) -> Vec3 {
     let a = Vec3::new(format!("{a}_x").into(), format!("{a}_y").into(), 1.into());
     let b = Vec3::new(format!("{b}_x").into(), format!("{b}_y").into(), 1.into());
-    let ra = rotate(&a, &n, c, s);
+    let ra = rotate(&a, n, c, s);
     ra.cross(&b)
 }
 
@@ -0,0 +1,59 @@
+use mathguru::{Base, Poly, Sym};
+
+#[test]
+fn test_pow() {
+    let parse = |text: &str| Poly::<i64>::parse(text).unwrap();
+    let p = parse("a + b");
+    assert_eq!(p.pow(0), parse("1"));
+    assert_eq!(p.pow(1), p);
+    assert_eq!(p.pow(3), parse("a^3 + 3 a^2 b + 3 a b^2 + b^3"));
+    assert_eq!(parse("0").pow(2), parse("0"));
+
+    // central binomial coefficient
+    let q = parse("x + 1").pow(10);
+    let x5 = q
+        .terms
+        .iter()
+        .find(|term| term.power_of(&Base::Sym(Sym::from("x".to_string()))) == 5)
+        .unwrap();
+    assert_eq!(x5.coeff, 252);
+    assert_eq!(q.terms.len(), 11);
+
+    assert_eq!(
+        parse("x - y").pow(2) * parse("x + y").pow(2),
+        parse("x^2 - y^2").pow(2)
+    );
+
+    let m = parse("- 2 x^2 y^-1").terms[0].clone();
+    assert_eq!(Poly::from(m.pow(3)), parse("- 8 x^6 y^-3"));
+    assert_eq!(Poly::from(m.pow(0)), parse("1"));
+}
+
+#[test]
+fn test_expand_powers() {
+    let parse = |text: &str| Poly::<i64>::parse(text).unwrap();
+    let mut p = parse("x (a + b)^2 + (a - b)^3");
+    p.expand();
+    p.merge_terms();
+    assert_eq!(
+        p,
+        parse("a^2 x + 2 a b x + b^2 x + a^3 - 3 a^2 b + 3 a b^2 - b^3")
+    );
+
+    // nested polynomials are expanded as well
+    let mut q = parse("((x + 1)^2 + y)^2");
+    q.expand();
+    q.merge_terms();
+    let mut expected = parse("x^2 + 2 x + 1 + y").pow(2);
+    expected.merge_terms();
+    assert_eq!(q, expected);
+
+    // negative powers are kept
+    let mut r = parse("(a + b)^2 (a - b)^-1");
+    r.expand();
+    r.merge_terms();
+    assert_eq!(
+        r,
+        parse("a^2 (a - b)^-1 + 2 a b (a - b)^-1 + b^2 (a - b)^-1")
+    );
+}
@@ -1,4 +1,4 @@
-use mathguru::{Poly, Quat, Vector3};
+use mathguru::{Poly, Quat, ToLatex, Vector3};
 
 type Vec3 = Vector3<Poly<i32>>;
 
@@ -52,11 +52,7 @@ fn test_quaternion() {
                     print!("p_{{{}}} ", index + 1);
                 }
                 _ => {
-                    if factor.power == 1 {
-                        print!("{} ", factor.base);
-                    } else {
-                        print!("{}^{} ", factor.base, factor.power);
-                    }
+                    print!("{} ", factor.to_latex());
                 }
             }
         }
@@ -0,0 +1,53 @@
+use mathguru::{Poly, RationalFunction};
+use num_traits::{One, Zero};
+
+type Rf = RationalFunction<i32>;
+
+fn rf(numer: &str, denom: &str) -> Rf {
+    RationalFunction::new(Poly::parse(numer).unwrap(), Poly::parse(denom).unwrap())
+}
+
+#[test]
+fn test_normalize() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    // common factors and the sign of the denominator
+    let r = rf("x^2 - 1", "- 2 x - 2");
+    assert_eq!(r, rf("1 - x", "2"));
+    assert_eq!(r.denom(), &parse("2"));
+    assert_eq!(rf("6 x y", "4 x^2"), rf("3 y", "2 x"));
+    assert_eq!(rf("0", "x + 1"), Rf::zero());
+    assert_eq!(rf("x - y", "y - x"), -Rf::one());
+
+    // negative powers are cleared
+    assert_eq!(
+        RationalFunction::from(parse("x^-1 + y^-1")),
+        rf("x + y", "x y")
+    );
+    assert_eq!(rf("a + b", "1").to_poly(), Some(parse("a + b")));
+    assert_eq!(rf("a + b", "a").to_poly(), None);
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = rf("1", "x - 1");
+    let b = rf("1", "x + 1");
+    assert_eq!(a.clone() + b.clone(), rf("2 x", "x^2 - 1"));
+    assert_eq!(a.clone() - b.clone(), rf("2", "x^2 - 1"));
+    assert_eq!(a.clone() * b.clone(), rf("1", "x^2 - 1"));
+    assert_eq!(a.clone() / b.clone(), rf("x + 1", "x - 1"));
+    assert_eq!(a.clone() - a.clone(), Rf::zero());
+    assert_eq!(a.recip(), Some(rf("x - 1", "1")));
+    assert_eq!(Rf::zero().recip(), None);
+
+    // x / (x + y) + y / (x + y) = 1
+    let c = rf("x", "x + y") + rf("y", "x + y");
+    assert!(c.is_one());
+    assert_eq!(c.to_string(), "1 ");
+    assert_eq!(rf("2", "x - 1").to_string(), "(2 ) / (- 1  + x )");
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_division_by_zero() {
+    let _ = rf("1", "x") / Rf::zero();
+}
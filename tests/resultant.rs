@@ -0,0 +1,92 @@
+use mathguru::{Matrix, Poly};
+use num_traits::Zero;
+
+#[test]
+fn test_resultant() {
+    let x: Poly<i32> = Poly::from("x");
+    let (a, b, c, d): (Poly<i32>, Poly<i32>, Poly<i32>, Poly<i32>) =
+        ("a".into(), "b".into(), "c".into(), "d".into());
+    let zero = Poly::zero();
+    let one: Poly<i32> = 1.into();
+
+    // Sylvester matrix of x^2 + a x + b and c x^2 + d x + 1
+    let f = x.clone() * x.clone() + a.clone() * x.clone() + b.clone();
+    let g = c.clone() * x.clone() * x.clone() + d.clone() * x.clone() + one.clone();
+    let sylvester = Matrix::<Poly<i32>, 4, 4>::new(
+        one.clone(),
+        a.clone(),
+        b.clone(),
+        zero.clone(),
+        zero.clone(),
+        one.clone(),
+        a.clone(),
+        b.clone(),
+        c.clone(),
+        d.clone(),
+        one.clone(),
+        zero.clone(),
+        zero.clone(),
+        c.clone(),
+        d.clone(),
+        one.clone(),
+    );
+    let mut det = sylvester.determinant();
+    det.merge_terms();
+    assert_eq!(f.resultant(&g, &"x".into()), det);
+    // res(g, f) = (-1)^(2 2) res(f, g)
+    assert_eq!(g.resultant(&f, &"x".into()), det);
+
+    // common root x = 1
+    let h = (x.clone() - one.clone()) * (x.clone() + a.clone());
+    let k = (x.clone() - one.clone()) * (x.clone() * x.clone() + b.clone());
+    assert!(h.resultant(&k, &"x".into()).is_zero());
+
+    // degrees 1 and 3 give an odd sign when swapped
+    let l = x.clone() - a.clone();
+    let cubic = x.clone() * x.clone() * x.clone() + b.clone();
+    let value = a.clone() * a.clone() * a.clone() + b.clone();
+    assert_eq!(cubic.resultant(&l, &"x".into()), -value.clone());
+    assert_eq!(l.resultant(&cubic, &"x".into()), value);
+
+    // constant with respect to x
+    assert_eq!(
+        a.resultant(&cubic, &"x".into()),
+        a.clone() * a.clone() * a.clone()
+    );
+
+    // eliminate y between two circles meeting at (0, 0) and (1, 1)
+    let y: Poly<i32> = Poly::from("y");
+    let c1 = x.clone() * x.clone() + y.clone() * y.clone() - Poly::from(2) * x.clone();
+    let c2 = x.clone() * x.clone() + y.clone() * y.clone() - Poly::from(2) * y.clone();
+    let res = c1.resultant(&c2, &"y".into());
+    assert_eq!(
+        res,
+        Poly::from(8) * x.clone() * x.clone() - Poly::from(8) * x.clone()
+    );
+}
+
+#[test]
+fn test_discriminant() {
+    let x: Poly<i32> = Poly::from("x");
+    let a: Poly<i32> = Poly::from("a");
+    let b: Poly<i32> = Poly::from("b");
+    let c: Poly<i32> = Poly::from("c");
+    let p: Poly<i32> = Poly::from("p");
+    let q: Poly<i32> = Poly::from("q");
+
+    let quadratic = a.clone() * x.clone() * x.clone() + b.clone() * x.clone() + c.clone();
+    assert_eq!(
+        quadratic.discriminant(&"x".into()),
+        b.clone() * b.clone() - Poly::from(4) * a.clone() * c.clone()
+    );
+
+    let cubic = x.clone() * x.clone() * x.clone() + p.clone() * x.clone() + q.clone();
+    assert_eq!(
+        cubic.discriminant(&"x".into()),
+        Poly::from(-4) * p.clone() * p.clone() * p.clone() - Poly::from(27) * q.clone() * q.clone()
+    );
+
+    // repeated root
+    let square = (x.clone() - a.clone()) * (x.clone() - a.clone()) * (x.clone() + b.clone());
+    assert!(square.discriminant(&"x".into()).is_zero());
+}
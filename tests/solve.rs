@@ -0,0 +1,133 @@
+use mathguru::{Matrix, Poly, RationalFunction, SolveError, Vector, Vector3, Zp};
+
+type Vector2<T> = Vector<T, 2>;
+
+#[test]
+fn test_solve_float() {
+    let m = Matrix::<f64, 3, 3>::new(0.0, 2.0, 1.0, 1.0, 1.0, 1.0, 4.0, -1.0, 2.0);
+    let x = m.solve(&Vector3::new(7.0, 6.0, 8.0)).unwrap();
+    for (value, expected) in x.data.iter().zip([1.0, 2.0, 3.0]) {
+        assert!((value - expected).abs() < 1e-12);
+    }
+
+    let singular = Matrix::<f64, 3, 3>::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let b = Vector3::new(6.0, 15.0, 24.0);
+    assert_eq!(singular.solve(&b), Err(SolveError::Singular));
+    let b = Vector3::new(1.0, 0.0, 0.0);
+    assert_eq!(singular.solve(&b), Err(SolveError::Inconsistent));
+
+    // overdetermined, consistent and not
+    let tall = Matrix::<f64, 3, 2>::new(1.0, 1.0, 1.0, -1.0, 2.0, 0.0);
+    let x = tall.solve(&Vector3::new(3.0, 1.0, 4.0)).unwrap();
+    assert_eq!(x, Vector2::new(2.0, 1.0));
+    let b = Vector3::new(3.0, 1.0, 5.0);
+    assert_eq!(tall.solve(&b), Err(SolveError::Inconsistent));
+    assert_eq!(
+        SolveError::Inconsistent.to_string(),
+        "inconsistent system, no solution"
+    );
+}
+
+#[test]
+fn test_solve_zp() {
+    let z = Zp::<13>::new;
+    let m = Matrix::<Zp<13>, 2, 2>::new(z(0), z(3), z(2), z(5));
+    let b = Vector2::new(z(1), z(2));
+    let x = m.solve(&b).unwrap();
+    assert_eq!(&m * &x, b);
+}
+
+#[test]
+fn test_solve_symbolic() {
+    let parse = |text: &str| Poly::<i64>::parse(text).unwrap();
+    // [[a, 1], [1, a]] x = [1, 0]
+    let m = Matrix::<Poly<i64>, 2, 2>::new(parse("a"), parse("1"), parse("1"), parse("a"));
+    let x = m.solve(&Vector2::new(parse("1"), parse("0"))).unwrap();
+    let expected = Vector2::new(
+        RationalFunction::new(parse("a"), parse("a^2 - 1")),
+        RationalFunction::new(parse("-1"), parse("a^2 - 1")),
+    );
+    assert_eq!(x, expected);
+
+    // generic 3x3 system, check A x = b
+    let names = ["a", "b", "c", "d", "e", "f", "g", "h", "k"];
+    let m = Matrix::<Poly<i64>, 3, 3> {
+        data: std::array::from_fn(|c| std::array::from_fn(|r| parse(names[r * 3 + c]))),
+    };
+    let b = Vector3::new(parse("u"), parse("v"), parse("1"));
+    let x = m.solve(&b).unwrap();
+    for r in 0..3 {
+        let mut sum = RationalFunction::from(parse("0"));
+        for c in 0..3 {
+            sum = sum + RationalFunction::from(m.data[c][r].clone()) * x.data[c].clone();
+        }
+        assert_eq!(sum, RationalFunction::from(b.data[r].clone()));
+    }
+
+    let singular = Matrix::<Poly<i64>, 2, 2>::new(parse("x"), parse("x y"), parse("1"), parse("y"));
+    let b = Vector2::new(parse("x"), parse("1"));
+    assert_eq!(singular.solve(&b), Err(SolveError::Singular));
+    let b = Vector2::new(parse("1"), parse("0"));
+    assert_eq!(singular.solve(&b), Err(SolveError::Inconsistent));
+}
+
+#[test]
+fn test_lu() {
+    let z = Zp::<13>::new;
+    let m = Matrix::<Zp<13>, 3, 3>::new(z(0), z(2), z(1), z(1), z(1), z(1), z(4), z(12), z(2));
+    let lu = m.lu();
+    assert_eq!(lu.rank(), 3);
+    assert_eq!(lu.determinant(), m.determinant());
+    // P A = L U
+    for (i, &r) in lu.permutation.iter().enumerate() {
+        for c in 0..3 {
+            let mut value = z(0);
+            for k in 0..=i.min(c) {
+                let lower = if k == i { z(1) } else { lu.matrix.data[k][i] };
+                value += lower * lu.matrix.data[c][k];
+            }
+            assert_eq!(value, m.data[c][r]);
+        }
+    }
+    // one factorization, several right-hand sides
+    for b in [
+        Vector3::new(z(1), z(0), z(0)),
+        Vector3::new(z(3), z(7), z(5)),
+    ] {
+        assert_eq!(&m * &lu.solve(&b).unwrap(), b);
+    }
+
+    let f = Matrix::<f64, 2, 2>::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(f.lu().permutation, [1, 0]);
+    assert!((f.lu().determinant() + 2.0).abs() < 1e-12);
+    let singular = Matrix::<f64, 2, 2>::new(1.0, 2.0, 2.0, 4.0);
+    assert_eq!(singular.lu().rank(), 1);
+    assert_eq!(singular.lu().determinant(), 0.0);
+}
+
+#[test]
+fn test_solve_symbolic_pivoting() {
+    let parse = |text: &str| Poly::<i64>::parse(text).unwrap();
+    // zero leading entry, [[0, x, 1], [y, 1, 0], [1, 0, y]] x = [1, x, 0]
+    let m = Matrix::<Poly<i64>, 3, 3>::new(
+        parse("0"),
+        parse("x"),
+        parse("1"),
+        parse("y"),
+        parse("1"),
+        parse("0"),
+        parse("1"),
+        parse("0"),
+        parse("y"),
+    );
+    let b = Vector3::new(parse("1"), parse("x"), parse("0"));
+    let x = m.solve(&b).unwrap();
+    for r in 0..3 {
+        let mut sum = RationalFunction::from(parse("0"));
+        for c in 0..3 {
+            sum = sum + RationalFunction::from(m.data[c][r].clone()) * x.data[c].clone();
+        }
+        assert_eq!(sum, RationalFunction::from(b.data[r].clone()));
+    }
+    assert_eq!(x.data[2].denom(), &parse("x y^2 + 1"));
+}
@@ -0,0 +1,91 @@
+use mathguru::{Base, EvalError, Poly, Sym, Vector3};
+use std::collections::HashMap;
+
+type Vec3 = Vector3<Poly<i32>>;
+
+fn sym(name: &str) -> Base<i32> {
+    Base::Sym(Sym::from(name.to_string()))
+}
+
+fn rotate(a: &Vec3, n: &Vec3, sin: Poly<i32>, cos: Poly<i32>) -> Vec3 {
+    let para = n * n.dot(a);
+    let perp = a - &para;
+    para + perp * cos + n.cross(a) * sin
+}
+
+#[test]
+fn test_subs() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    let p = parse("x^2 y + 3 x^-1 + z");
+    let map = HashMap::from([(sym("x"), parse("2 y")), (sym("z"), parse("a + b"))]);
+    assert_eq!(p.subs(&map).unwrap(), parse("4 y^3 + 3 (2 y)^-1 + a + b"));
+
+    let map = HashMap::from([(sym("x"), parse("a + b"))]);
+    assert_eq!(
+        p.subs(&map).unwrap(),
+        parse("a^2 y + 2 a b y + b^2 y + 3 (a + b)^-1 + z")
+    );
+    let map = HashMap::from([(sym("x"), parse("- a"))]);
+    assert_eq!(p.subs(&map).unwrap(), parse("a^2 y - 3 a^-1 + z"));
+    let map = HashMap::from([(sym("y"), parse("0"))]);
+    assert_eq!(p.subs(&map).unwrap(), parse("3 x^-1 + z"));
+    let map = HashMap::from([(sym("x"), parse("0"))]);
+    assert_eq!(p.subs(&map), Err(EvalError::DivisionByZero));
+    let map = HashMap::from([(sym("x"), parse("y - y"))]);
+    assert_eq!(
+        parse("sin(x^-1)").subs(&map),
+        Err(EvalError::DivisionByZero)
+    );
+
+    // inside function parameters and nested polynomials
+    let q = parse("sin(x + 1) (x y + 1)^2");
+    let map = HashMap::from([(sym("x"), parse("t"))]);
+    assert_eq!(q.subs(&map).unwrap(), parse("sin(1 + t) (1 + t y)^2"));
+
+    // whole applications, matched after substituting inside them
+    let sin = parse("sinθ").terms[0].factors[0].base.clone();
+    let map = HashMap::from([(sin, parse("s"))]);
+    assert_eq!(
+        parse("2 sin^3θ cosθ").subs(&map).unwrap(),
+        parse("2 s^3 cosθ")
+    );
+    let map = HashMap::from([
+        (sym("x"), parse("θ")),
+        (parse("cosθ").terms[0].factors[0].base.clone(), parse("c")),
+    ]);
+    assert_eq!(parse("cos^2x + x").subs(&map).unwrap(), parse("c^2 + θ"));
+}
+
+#[test]
+fn test_subs_between_forms() {
+    let parse = |text: &str| Poly::<i32>::parse(text).unwrap();
+    let a = Vec3::new("a_x".into(), "a_y".into(), 1.into());
+    let n = Vec3::new("n_x".into(), "n_y".into(), "n_z".into());
+    let theta = Poly::<i32>::from("θ");
+
+    let applied = rotate(&a, &n, theta.apply("sin"), theta.apply("cos"));
+    let symbolic = rotate(&a, &n, "s".into(), "c".into());
+
+    let to_symbols = HashMap::from([
+        (
+            theta.apply("sin").terms[0].factors[0].base.clone(),
+            parse("s"),
+        ),
+        (
+            theta.apply("cos").terms[0].factors[0].base.clone(),
+            parse("c"),
+        ),
+    ]);
+    let to_functions = HashMap::from([
+        (sym("s"), theta.apply("sin")),
+        (sym("c"), theta.apply("cos")),
+    ]);
+    for i in 0..3 {
+        let mut expected = symbolic.data[i].clone();
+        expected.merge_terms();
+        assert_eq!(applied.data[i].subs(&to_symbols).unwrap(), expected);
+        let mut expected = applied.data[i].clone();
+        expected.merge_terms();
+        assert_eq!(symbolic.data[i].subs(&to_functions).unwrap(), expected);
+    }
+}
@@ -0,0 +1,65 @@
+use mathguru::{Coeff, Matrix, Poly, Sym, Vector3, Zp};
+use num_traits::{Num, One, Zero};
+use std::collections::HashMap;
+
+type F7 = Zp<7>;
+
+#[test]
+fn test_zp_arithmetic() {
+    let a = F7::new(5);
+    let b = F7::new(-4);
+    assert_eq!(b.value(), 3);
+    assert_eq!(a + b, F7::new(1));
+    assert_eq!(a - b, F7::new(2));
+    assert_eq!(a * b, F7::new(1));
+    assert_eq!(a / b, F7::new(4));
+    assert_eq!(-a, F7::new(2));
+    assert_eq!(a.inv(), Some(b));
+    assert_eq!(F7::zero().inv(), None);
+    assert_eq!(a.pow(6), F7::one());
+    assert_eq!(F7::from_str_radix("3/2", 10), Ok(F7::new(5)));
+    assert!(F7::from_str_radix("1/0", 10).is_err());
+
+    // no overflow near u64::MAX
+    type Big = Zp<18446744073709551557>;
+    let c = Big::new(-1);
+    assert_eq!(c * c, Big::one());
+}
+
+#[test]
+fn test_zp_poly() {
+    let parse = |text: &str| Poly::<F7>::parse(text).unwrap();
+    // Frobenius: (x + y)^7 = x^7 + y^7 over GF(7)
+    let expected = parse("x^7 + y^7");
+    assert_eq!(parse("x + y").pow(7), expected);
+    assert!(parse("x^7 + 3").diff(&Sym::from("x")).terms.is_empty());
+    // 1 / 2 = 4 in GF(7)
+    assert_eq!(
+        parse("sqrt(x)").diff(&Sym::from("x")),
+        parse("4 sqrt(x)^-1")
+    );
+    assert_eq!(parse("-1 x").to_string(), "6 x ");
+    assert!(!F7::new(-1).is_negative());
+    assert!(Coeff::is_negative(&-1));
+
+    // (x + 1)(x - 1) = x^2 - 1 at random points
+    let lhs = parse("x + 1") * parse("x - 1");
+    let rhs = parse("x^2 - 1");
+    for value in [0, 3, 6] {
+        let map = HashMap::from([(Sym::from("x").into(), parse(&value.to_string()))]);
+        let mut left = lhs.subs(&map).unwrap();
+        let mut right = rhs.subs(&map).unwrap();
+        left.merge_terms();
+        right.merge_terms();
+        assert_eq!(left, right);
+    }
+}
+
+#[test]
+fn test_zp_linear_algebra() {
+    let n = F7::new;
+    let m = Matrix::<F7, 2, 2>::new(n(1), n(2), n(3), n(4));
+    assert_eq!(m.determinant(), n(5));
+    let v = Vector3::new(n(1), n(2), n(3));
+    assert_eq!(v.dot(&v), n(0));
+}